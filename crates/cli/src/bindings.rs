@@ -0,0 +1,707 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt::Write,
+    fs,
+    path::PathBuf,
+};
+
+use serde::{de::IgnoredAny, Deserialize};
+
+#[cfg(test)]
+mod tests;
+
+const BINDINGS_HEADER: &str = "\
+// This file was generated by `roblox-rs bindings` from a Roblox API dump, and shouldn't be edited by hand.
+#![allow(dead_code, non_camel_case_types, clippy::all)]
+
+use roblox_rs::prelude::*;
+
+#[luau]
+extern \"C\" {
+    pub type RBXScriptSignal;
+    pub type RBXScriptConnection;
+
+    #[luau(method = \"Disconnect\")]
+    fn rbx_script_connection_disconnect(this: &RBXScriptConnection);
+}
+
+impl RBXScriptConnection {
+    pub fn disconnect(&self) {
+        rbx_script_connection_disconnect(self)
+    }
+}
+";
+
+const ENUMS_HEADER: &str = "\
+/// Roblox enums are received from Luau as an `EnumItem`, and passed to Luau as the number of the item.
+pub mod enums {
+    macro_rules! roblox_enum {
+        ($name:ident { $($item:ident = $value:literal,)* }) => {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #[repr(u32)]
+            pub enum $name {
+                $($item = $value,)*
+            }
+
+            const _: () = {
+                use roblox_rs::internal::*;
+
+                impl WasmDescribe for $name {
+                    #[inline(always)]
+                    fn describe() {
+                        describe(ENUM_ITEM);
+                    }
+                }
+
+                impl WasmIntoAbi for $name {
+                    type Abi = u32;
+
+                    fn into_abi(self) -> Self::Abi {
+                        self as u32
+                    }
+                }
+
+                impl WasmFromAbi for $name {
+                    type Abi = u32;
+
+                    unsafe fn from_abi(value: Self::Abi) -> Self {
+                        match value {
+                            $($value => $name::$item,)*
+                            _ => panic!(\"unknown {} value {value}\", stringify!($name)),
+                        }
+                    }
+                }
+            };
+        };
+    }
+";
+
+// Types which are declared by the header, so they aren't declared again when a member uses them.
+const HEADER_TYPES: &[&str] = &["RBXScriptSignal", "RBXScriptConnection"];
+
+// Closures can only receive up to four arguments, so events and callbacks with more are skipped.
+const MAX_CLOSURE_ARGS: usize = 4;
+
+// Names which would shadow a trait method of every class, or can't be written as a raw identifier.
+const RESERVED_NAMES: &[&str] = &[
+    "clone", "as_ref", "into", "from", "self", "Self", "super", "crate",
+];
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "gen", "macro", "override", "priv", "try",
+    "typeof", "unsized", "virtual", "yield",
+];
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiDump {
+    classes: Vec<ApiClass>,
+    enums: Vec<ApiEnum>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiClass {
+    name: String,
+    superclass: String,
+    members: Vec<ApiMember>,
+    #[serde(default)]
+    tags: Vec<ApiTag>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "MemberType")]
+enum ApiMember {
+    Property(ApiProperty),
+    Function(ApiFunction),
+    Event(ApiEvent),
+    Callback(ApiFunction),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiProperty {
+    name: String,
+    value_type: ApiType,
+    security: ApiPropertySecurity,
+    #[serde(default)]
+    tags: Vec<ApiTag>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiPropertySecurity {
+    read: String,
+    write: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiFunction {
+    name: String,
+    parameters: Vec<ApiParameter>,
+    return_type: ApiType,
+    security: String,
+    #[serde(default)]
+    tags: Vec<ApiTag>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiEvent {
+    name: String,
+    parameters: Vec<ApiParameter>,
+    security: String,
+    #[serde(default)]
+    tags: Vec<ApiTag>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiParameter {
+    name: String,
+    #[serde(rename = "Type")]
+    ty: ApiType,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiType {
+    category: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiEnum {
+    name: String,
+    items: Vec<ApiEnumItem>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiEnumItem {
+    name: String,
+    value: i64,
+}
+
+/// Most tags are plain names, but newer dumps also contain descriptor objects.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ApiTag {
+    Name(String),
+    Descriptor(IgnoredAny),
+}
+
+fn has_tag(tags: &[ApiTag], name: &str) -> bool {
+    tags.iter()
+        .any(|tag| matches!(tag, ApiTag::Name(tag) if tag == name))
+}
+
+/// Where a value appears in the bindings, which decides whether it's borrowed or owned.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Argument,
+    Setter,
+    Result,
+    ClosureArgument,
+    ClosureResult,
+}
+
+impl Position {
+    fn is_borrowed(self) -> bool {
+        matches!(self, Position::Argument | Position::Setter)
+    }
+}
+
+struct Generator {
+    plugin: bool,
+    classes: HashSet<String>,
+    enums: HashSet<String>,
+    data_types: BTreeSet<String>,
+}
+
+impl Generator {
+    fn is_accessible(&self, security: &str) -> bool {
+        security == "None" || (self.plugin && security == "PluginSecurity")
+    }
+
+    fn rust_type(&mut self, ty: &ApiType, position: Position) -> String {
+        let name = ty.name.trim_end_matches('?');
+        let borrow = if position.is_borrowed() { "&" } else { "" };
+
+        match (ty.category.as_str(), name) {
+            ("Primitive", "bool") => "bool".to_string(),
+            ("Primitive", "int") => "i32".to_string(),
+            ("Primitive", "int64") => "i64".to_string(),
+            ("Primitive", "float") => "f32".to_string(),
+            ("Primitive", "double") => "f64".to_string(),
+            ("Primitive", "string") | ("DataType", "ContentId") if position.is_borrowed() => {
+                "&str".to_string()
+            }
+            ("Primitive", "string") | ("DataType", "ContentId") => "String".to_string(),
+            ("Primitive", "void" | "null") => "()".to_string(),
+            // Instances can be nil, such as an unset `Parent`, so they're optional unless they're an argument.
+            ("Class", name) if self.classes.contains(name) => match position {
+                Position::Argument => format!("&{name}"),
+                Position::Setter => format!("Option<&{name}>"),
+                _ => format!("Option<{name}>"),
+            },
+            ("Enum", name) if self.enums.contains(name) => format!("enums::{name}"),
+            ("DataType", "Objects") if position.is_borrowed() => "&[Instance]".to_string(),
+            ("DataType", "Objects") => "Vec<Instance>".to_string(),
+            ("Group", "Array") if position.is_borrowed() => "&[LuauValue]".to_string(),
+            ("Group", "Array") => "Vec<LuauValue>".to_string(),
+            // These data types are plain Rust structs, which are passed by value.
+            (
+                "DataType",
+                name @ ("Vector2" | "Vector3" | "CFrame" | "Color3" | "UDim2" | "Rect"),
+            ) => name.to_string(),
+            ("DataType", name) if name != "Function" && is_identifier(name) => {
+                if !self.classes.contains(name) && !HEADER_TYPES.contains(&name) {
+                    self.data_types.insert(name.to_string());
+                }
+
+                format!("{borrow}{name}")
+            }
+            // Functions, tuples, dictionaries and variants don't have a fixed type.
+            _ => format!("{borrow}LuauValue"),
+        }
+    }
+
+    /// The parameters of a method, where a trailing tuple is unpacked as the remaining arguments.
+    fn parameters(&mut self, parameters: &[ApiParameter]) -> Vec<(String, String)> {
+        let mut names = HashSet::new();
+
+        parameters
+            .iter()
+            .enumerate()
+            .map(|(index, parameter)| {
+                let is_last = index == parameters.len() - 1;
+                let ty = match (parameter.ty.category.as_str(), parameter.ty.name.as_str()) {
+                    ("Group", "Tuple") if is_last => "Variadic<LuauValue>".to_string(),
+                    _ => self.rust_type(&parameter.ty, Position::Argument),
+                };
+
+                let mut name = rust_identifier(&snake_case(&parameter.name));
+                if !names.insert(name.clone()) || name == "this" {
+                    name = format!("arg{index}");
+                }
+
+                (name, ty)
+            })
+            .collect()
+    }
+
+    /// A closure type, or `None` if it has too many arguments to be represented.
+    fn closure_type(
+        &mut self,
+        parameters: &[ApiParameter],
+        output: Option<&ApiType>,
+    ) -> Option<String> {
+        if parameters.len() > MAX_CLOSURE_ARGS {
+            return None;
+        }
+
+        let args: Vec<_> = parameters
+            .iter()
+            .map(|parameter| self.rust_type(&parameter.ty, Position::ClosureArgument))
+            .collect();
+        let output = output
+            .map(|ty| self.rust_type(ty, Position::ClosureResult))
+            .filter(|ty| ty != "()")
+            .map(|ty| format!(" -> {ty}"))
+            .unwrap_or_default();
+
+        Some(format!("Closure<dyn FnMut({}){output}>", args.join(", ")))
+    }
+
+    fn write_class(&mut self, out: &mut String, class: &ApiClass) {
+        let name = &class.name;
+        let prefix = snake_case(name);
+
+        let mut imports = String::new();
+        let mut methods = String::new();
+
+        // Names are shared between the members of a class, so the first member with a name wins.
+        // Import names are prefixed with the kind of member, so a property and a function with the same name don't clash.
+        let mut used = HashSet::new();
+        let mut claim = |name: &str| used.insert(name.to_string());
+
+        if has_tag(&class.tags, "Service") {
+            claim("get_service");
+            writeln!(
+                imports,
+                "\n    #[luau(namespace = \"game\", method = \"GetService\")]"
+            )
+            .unwrap();
+            writeln!(
+                imports,
+                "    fn {prefix}_service(class_name: &str) -> {name};"
+            )
+            .unwrap();
+            writeln!(methods, "\n    pub fn get_service() -> Self {{").unwrap();
+            writeln!(methods, "        {prefix}_service(\"{name}\")").unwrap();
+            writeln!(methods, "    }}").unwrap();
+        } else if !has_tag(&class.tags, "NotCreatable") {
+            claim("new");
+            writeln!(imports, "\n    #[luau(name = \"Instance.new\")]").unwrap();
+            writeln!(imports, "    fn {prefix}_new(class_name: &str) -> {name};").unwrap();
+            writeln!(methods, "\n    pub fn new() -> Self {{").unwrap();
+            writeln!(methods, "        {prefix}_new(\"{name}\")").unwrap();
+            writeln!(methods, "    }}").unwrap();
+        }
+
+        for member in &class.members {
+            match member {
+                ApiMember::Property(property) => {
+                    if is_hidden(&property.tags) {
+                        continue;
+                    }
+
+                    let member = snake_case(&property.name);
+                    let luau_name = &property.name;
+
+                    if self.is_accessible(&property.security.read)
+                        && !has_tag(&property.tags, "WriteOnly")
+                        && claim(&member)
+                    {
+                        let ty = self.rust_type(&property.value_type, Position::Result);
+                        let method = rust_identifier(&member);
+
+                        writeln!(imports, "\n    #[luau(get = \"{luau_name}\")]").unwrap();
+                        writeln!(
+                            imports,
+                            "    fn {prefix}_prop_get_{member}(this: &{name}) -> {ty};"
+                        )
+                        .unwrap();
+                        writeln!(methods, "\n    pub fn {method}(&self) -> {ty} {{").unwrap();
+                        writeln!(methods, "        {prefix}_prop_get_{member}(self)").unwrap();
+                        writeln!(methods, "    }}").unwrap();
+                    }
+
+                    if self.is_accessible(&property.security.write)
+                        && !has_tag(&property.tags, "ReadOnly")
+                        && claim(&format!("set_{member}"))
+                    {
+                        let ty = self.rust_type(&property.value_type, Position::Setter);
+
+                        writeln!(imports, "\n    #[luau(set = \"{luau_name}\")]").unwrap();
+                        writeln!(
+                            imports,
+                            "    fn {prefix}_prop_set_{member}(this: &{name}, value: {ty});"
+                        )
+                        .unwrap();
+                        writeln!(methods, "\n    pub fn set_{member}(&self, value: {ty}) {{")
+                            .unwrap();
+                        writeln!(methods, "        {prefix}_prop_set_{member}(self, value)")
+                            .unwrap();
+                        writeln!(methods, "    }}").unwrap();
+                    }
+                }
+                ApiMember::Function(function) => {
+                    let member = snake_case(&function.name);
+                    if is_hidden(&function.tags)
+                        || !self.is_accessible(&function.security)
+                        || !claim(&member)
+                    {
+                        continue;
+                    }
+
+                    let luau_name = &function.name;
+                    let method = rust_identifier(&member);
+                    let parameters = self.parameters(&function.parameters);
+                    // The API dump doesn't list the items of a returned tuple, so only its first value is returned.
+                    // Imports which know the items can return a Rust tuple instead, e.g `-> (bool, LuauValue)`.
+                    let output = match self.rust_type(&function.return_type, Position::Result) {
+                        ty if ty == "()" => String::new(),
+                        ty => format!(" -> {ty}"),
+                    };
+
+                    let import_args: Vec<_> = std::iter::once(format!("this: &{name}"))
+                        .chain(parameters.iter().map(|(arg, ty)| format!("{arg}: {ty}")))
+                        .collect();
+                    let method_args: Vec<_> = std::iter::once("&self".to_string())
+                        .chain(parameters.iter().map(|(arg, ty)| format!("{arg}: {ty}")))
+                        .collect();
+                    let call_args: Vec<_> = std::iter::once("self")
+                        .chain(parameters.iter().map(|(arg, _)| arg.as_str()))
+                        .collect();
+
+                    writeln!(imports, "\n    #[luau(method = \"{luau_name}\")]").unwrap();
+                    writeln!(
+                        imports,
+                        "    fn {prefix}_fn_{member}({}){output};",
+                        import_args.join(", ")
+                    )
+                    .unwrap();
+                    writeln!(
+                        methods,
+                        "\n    pub fn {method}({}){output} {{",
+                        method_args.join(", ")
+                    )
+                    .unwrap();
+                    writeln!(
+                        methods,
+                        "        {prefix}_fn_{member}({})",
+                        call_args.join(", ")
+                    )
+                    .unwrap();
+                    writeln!(methods, "    }}").unwrap();
+                }
+                ApiMember::Event(event) => {
+                    let member = snake_case(&event.name);
+                    if is_hidden(&event.tags)
+                        || !self.is_accessible(&event.security)
+                        || !claim(&format!("on_{member}"))
+                    {
+                        continue;
+                    }
+
+                    let Some(callback) = self.closure_type(&event.parameters, None) else {
+                        continue;
+                    };
+
+                    let luau_name = &event.name;
+
+                    writeln!(imports, "\n    #[luau(get = \"{luau_name}\")]").unwrap();
+                    writeln!(
+                        imports,
+                        "    fn {prefix}_event_{member}_signal(this: &{name}) -> RBXScriptSignal;"
+                    )
+                    .unwrap();
+                    writeln!(imports, "\n    #[luau(method = \"Connect\")]").unwrap();
+                    writeln!(
+                        imports,
+                        "    fn {prefix}_event_{member}_connect(signal: &RBXScriptSignal, callback: &{callback}) -> RBXScriptConnection;"
+                    )
+                    .unwrap();
+                    writeln!(
+                        methods,
+                        "\n    pub fn on_{member}(&self, callback: &{callback}) -> RBXScriptConnection {{"
+                    )
+                    .unwrap();
+                    writeln!(methods, "        {prefix}_event_{member}_connect(&{prefix}_event_{member}_signal(self), callback)").unwrap();
+                    writeln!(methods, "    }}").unwrap();
+                }
+                ApiMember::Callback(callback) => {
+                    let member = snake_case(&callback.name);
+                    if is_hidden(&callback.tags)
+                        || !self.is_accessible(&callback.security)
+                        || !claim(&format!("set_{member}"))
+                    {
+                        continue;
+                    }
+
+                    let Some(closure) =
+                        self.closure_type(&callback.parameters, Some(&callback.return_type))
+                    else {
+                        continue;
+                    };
+
+                    let luau_name = &callback.name;
+
+                    writeln!(imports, "\n    #[luau(set = \"{luau_name}\")]").unwrap();
+                    writeln!(
+                        imports,
+                        "    fn {prefix}_callback_set_{member}(this: &{name}, callback: &{closure});"
+                    )
+                    .unwrap();
+                    writeln!(
+                        methods,
+                        "\n    pub fn set_{member}(&self, callback: &{closure}) {{"
+                    )
+                    .unwrap();
+                    writeln!(
+                        methods,
+                        "        {prefix}_callback_set_{member}(self, callback)"
+                    )
+                    .unwrap();
+                    writeln!(methods, "    }}").unwrap();
+                }
+            }
+        }
+
+        writeln!(
+            out,
+            "\n#[luau]\nextern \"C\" {{\n    pub type {name};\n{imports}}}"
+        )
+        .unwrap();
+
+        if !methods.is_empty() {
+            writeln!(out, "\nimpl {name} {{{methods}}}").unwrap();
+        }
+
+        // Classes share the representation of their superclass, so they can be used wherever it's expected.
+        let superclass = &class.superclass;
+        if self.classes.contains(superclass) {
+            writeln!(
+                out,
+                "
+impl std::ops::Deref for {name} {{
+    type Target = {superclass};
+
+    fn deref(&self) -> &Self::Target {{
+        unsafe {{ &*(self as *const Self as *const {superclass}) }}
+    }}
+}}
+
+impl From<{name}> for {superclass} {{
+    fn from(value: {name}) -> Self {{
+        {superclass}(value.0)
+    }}
+}}"
+            )
+            .unwrap();
+        }
+    }
+}
+
+pub fn generate(api_dump: PathBuf, out: PathBuf, plugin: bool) {
+    let dump: ApiDump =
+        serde_json::from_slice(&fs::read(api_dump).expect("failed to read the API dump"))
+            .expect("failed to parse the API dump");
+
+    fs::write(out, generate_bindings(&dump, plugin)).expect("failed to write the bindings");
+}
+
+fn generate_bindings(dump: &ApiDump, plugin: bool) -> String {
+    // Enums without items can't be represented in Rust, so values of their type are left untyped.
+    let enums: Vec<_> = dump
+        .enums
+        .iter()
+        .filter(|v| is_identifier(&v.name) && v.items.iter().any(|item| item.value >= 0))
+        .collect();
+
+    let mut generator = Generator {
+        plugin,
+        classes: dump
+            .classes
+            .iter()
+            .map(|v| v.name.clone())
+            .filter(|v| is_identifier(v))
+            .collect(),
+        enums: enums.iter().map(|v| v.name.clone()).collect(),
+        data_types: BTreeSet::new(),
+    };
+
+    let mut classes = String::new();
+    for class in &dump.classes {
+        if generator.classes.contains(&class.name) {
+            generator.write_class(&mut classes, class);
+        }
+    }
+
+    let mut out_str = BINDINGS_HEADER.to_string();
+
+    // Data types which aren't given a Rust type are kept as distinct Luau values.
+    if !generator.data_types.is_empty() {
+        writeln!(out_str, "\n#[luau]\nextern \"C\" {{").unwrap();
+        for name in &generator.data_types {
+            writeln!(out_str, "    pub type {name};").unwrap();
+        }
+        writeln!(out_str, "}}").unwrap();
+    }
+
+    out_str.push_str(&classes);
+    write_enums(&mut out_str, &enums);
+
+    out_str
+}
+
+fn write_enums(out: &mut String, enums: &[&ApiEnum]) {
+    writeln!(out, "\n{ENUMS_HEADER}").unwrap();
+
+    for api_enum in enums {
+        let mut values = HashSet::new();
+        let mut names = HashSet::new();
+
+        writeln!(out, "    roblox_enum!({} {{", api_enum.name).unwrap();
+        for item in &api_enum.items {
+            // Aliases share the value of another item, and Rust doesn't allow duplicate discriminants.
+            let name = enum_item_identifier(&item.name);
+            if item.value < 0 || !values.insert(item.value) || !names.insert(name.clone()) {
+                continue;
+            }
+
+            writeln!(out, "        {name} = {},", item.value).unwrap();
+        }
+        writeln!(out, "    }});").unwrap();
+    }
+
+    writeln!(out, "}}").unwrap();
+}
+
+fn is_hidden(tags: &[ApiTag]) -> bool {
+    has_tag(tags, "Deprecated") || has_tag(tags, "Hidden") || has_tag(tags, "NotScriptable")
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|v| v.is_ascii_alphabetic() || v == '_')
+        && chars.all(|v| v.is_ascii_alphanumeric() || v == '_')
+}
+
+/// Converts a Roblox name into snake case, e.g `GetChildren` into `get_children` and `UIScale` into `ui_scale`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name
+        .chars()
+        .map(|v| if v.is_ascii_alphanumeric() { v } else { '_' })
+        .collect();
+    let mut out = String::new();
+
+    for (index, &char) in chars.iter().enumerate() {
+        if char.is_ascii_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next = chars.get(index + 1).copied().unwrap_or('_');
+            let starts_word = previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next.is_ascii_lowercase());
+
+            if starts_word {
+                out.push('_');
+            }
+        }
+
+        out.push(char.to_ascii_lowercase());
+    }
+
+    if out.starts_with(|v: char| v.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+
+    out
+}
+
+/// Escapes names which can't be used as a function or argument name.
+fn rust_identifier(name: &str) -> String {
+    if RESERVED_NAMES.contains(&name) {
+        format!("{name}_")
+    } else if KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+fn enum_item_identifier(name: &str) -> String {
+    let mut name: String = name
+        .chars()
+        .map(|v| if v.is_ascii_alphanumeric() { v } else { '_' })
+        .collect();
+
+    if !name.starts_with(|v: char| v.is_ascii_alphabetic()) {
+        name.insert(0, '_');
+    }
+
+    if name == "Self" {
+        name.push('_');
+    }
+
+    name
+}
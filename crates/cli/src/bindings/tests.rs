@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use syn::{ForeignItem, ImplItem, Item};
+
+use super::*;
+
+const API_DUMP: &str = include_str!("api-dump.json");
+
+fn bindings() -> String {
+    let dump: ApiDump = serde_json::from_str(API_DUMP).unwrap();
+    generate_bindings(&dump, false)
+}
+
+#[test]
+fn snake_case_names() {
+    assert_eq!(snake_case("GetChildren"), "get_children");
+    assert_eq!(snake_case("UIScale"), "ui_scale");
+    assert_eq!(snake_case("CFrame"), "c_frame");
+    assert_eq!(snake_case("targetCFrame"), "target_c_frame");
+    assert_eq!(snake_case("Vector3int16"), "vector3int16");
+    assert_eq!(snake_case("3DScale"), "_3_d_scale");
+    assert_eq!(snake_case("Font Size"), "font_size");
+}
+
+#[test]
+fn rust_identifiers() {
+    assert_eq!(rust_identifier("name"), "name");
+    assert_eq!(rust_identifier("type"), "r#type");
+    assert_eq!(rust_identifier("move"), "r#move");
+    assert_eq!(rust_identifier("clone"), "clone_");
+    assert_eq!(rust_identifier("self"), "self_");
+}
+
+#[test]
+fn enum_item_identifiers() {
+    assert_eq!(enum_item_identifier("Plastic"), "Plastic");
+    assert_eq!(enum_item_identifier("1080p"), "_1080p");
+    assert_eq!(enum_item_identifier("Self"), "Self_");
+    assert_eq!(enum_item_identifier("Semi-Bold"), "Semi_Bold");
+}
+
+/// Every item of the generated module shares one namespace, so declaring any name twice fails to compile.
+#[test]
+fn bindings_are_valid() {
+    let bindings = bindings();
+    let file = syn::parse_file(&bindings).expect("bindings should be valid Rust");
+
+    let mut items = HashSet::new();
+    let mut methods = HashSet::new();
+
+    for item in &file.items {
+        match item {
+            Item::ForeignMod(block) => {
+                for item in &block.items {
+                    let name = match item {
+                        ForeignItem::Fn(item) => item.sig.ident.to_string(),
+                        ForeignItem::Type(item) => item.ident.to_string(),
+                        _ => continue,
+                    };
+
+                    assert!(items.insert(name.clone()), "{name} is declared twice");
+                }
+            }
+            Item::Impl(block) if block.trait_.is_none() => {
+                let syn::Type::Path(class) = block.self_ty.as_ref() else {
+                    continue;
+                };
+                let class = &class.path.segments.last().unwrap().ident;
+
+                for item in &block.items {
+                    if let ImplItem::Fn(item) = item {
+                        let name = format!("{class}::{}", item.sig.ident);
+                        assert!(methods.insert(name.clone()), "{name} is declared twice");
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    assert!(items.contains("base_part_prop_get_mass"));
+    assert!(items.contains("base_part_fn_get_mass"));
+    assert!(methods.contains("Instance::get_property_changed_signal"));
+}
+
+#[test]
+fn data_types_are_passed_by_value() {
+    let bindings = bindings();
+
+    assert!(bindings.contains("pub fn position(&self) -> Vector3 {"));
+    assert!(bindings.contains("pub fn pivot_to(&self, target_c_frame: CFrame) {"));
+    assert!(!bindings.contains("pub type Vector3;"));
+    assert_eq!(bindings.matches("pub type RBXScriptSignal;").count(), 1);
+}
+
+#[test]
+fn enums_skip_aliases() {
+    let bindings = bindings();
+
+    assert!(bindings.contains("Plastic = 256,"));
+    assert!(!bindings.contains("Aliased = 256,"));
+    assert!(bindings.contains("_1080p = 0,"));
+    assert!(!bindings.contains("roblox_enum!(Empty"));
+}
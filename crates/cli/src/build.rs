@@ -1,5 +1,6 @@
-use std::{collections::HashSet, fs, io::Write, path::PathBuf};
+use std::{collections::HashSet, fs, io::Write, path::PathBuf, process};
 
+use roblox_rs_shared_context::shared_context::SharedImportKind;
 use walrus::{
     ir::{Call, Const, Instr, Value},
     Export, ExportItem, FunctionId, FunctionKind, Import, ImportKind, LocalFunction, Module,
@@ -19,7 +20,7 @@ use crate::{
 const ROJO_TEMPLATE: &str = include_str!("../rojo-template.json");
 const RUNNER_TEMPLATE: &str = include_str!("../runner-template.luau");
 
-pub fn build(mut module: Module, out: PathBuf) {
+pub fn build(mut module: Module, out: PathBuf, debug_heap: bool) {
     let describe_id = module.imports.iter().find_map(|v| match v.kind {
         ImportKind::Function(f) if v.module == "roblox-rs" && v.name == "describe" => Some(f),
         _ => None,
@@ -59,25 +60,35 @@ pub fn build(mut module: Module, out: PathBuf) {
             continue;
         };
 
+        check_tuples(&args, &return_type, "export", &export.rust_name);
+
         let export_name = export.export_name.to_string();
         let luau_name = export.luau_name.to_string();
+        let target = match &export.class {
+            Some(class) => format!("WASM_CLASSES[\"{class}\"]"),
+            None => "WASM_EXPORTS".to_string(),
+        };
         let output_type = *return_type.clone();
         let parameters = args;
         let body = Box::new(instructions::ExportBlock {
             inputs: parameters.clone(),
             output: output_type.clone(),
             body: Box::new(instructions::InvokeRustFunction {
-                function_name: export_name,
+                function: format!("WASM.func_list.{export_name}"),
                 parameters: parameters.clone(),
                 output_type: output_type.clone(),
             }),
         });
 
-        export_fns.push(instructions::WasmCreateExport {
-            luau_name,
-            parameters,
-            body,
-        })
+        export_fns.push((
+            export.rust_name.clone(),
+            instructions::WasmCreateExport {
+                target,
+                luau_name,
+                parameters,
+                body,
+            },
+        ))
     }
 
     for import in shared_context.imports.iter() {
@@ -105,26 +116,69 @@ pub fn build(mut module: Module, out: PathBuf) {
             continue;
         };
 
+        // Luau only expands the values of the last argument, so variadics can't appear anywhere else.
+        if let Some((_, rest)) = args.split_last() {
+            if rest.iter().any(|v| matches!(v, Describe::Variadic { .. })) {
+                panic!(
+                    "variadic arguments must be last, in import '{}'",
+                    import.rust_name
+                );
+            }
+        }
+
+        check_tuples(&args, &return_type, "import", &import.rust_name);
+
         let luau_name = import.luau_name.to_string();
         let export_name = import.export_name.to_string();
         let output = *return_type.clone();
         let parameters = args;
+        let parameter_count = parameters.len();
+        let result_count = luau_result_count(&output);
+        let namespace = import.namespace.clone();
+        let invoke: Box<dyn Instruction> = match import.kind {
+            SharedImportKind::Function => Box::new(instructions::InvokeLuauFunction {
+                namespace: namespace.clone(),
+                function_name: luau_name,
+                parameter_count,
+                result_count,
+            }),
+            SharedImportKind::Method => Box::new(instructions::InvokeLuauMethod {
+                object: namespace,
+                method_name: luau_name,
+                parameter_count,
+                result_count,
+            }),
+            SharedImportKind::Getter => Box::new(instructions::LuauGetProperty {
+                object: namespace,
+                property_name: luau_name,
+            }),
+            SharedImportKind::Setter => Box::new(instructions::LuauSetProperty {
+                object: namespace,
+                property_name: luau_name,
+            }),
+        };
+
+        // Fallible imports catch the Luau error, so it can be returned to Rust instead.
+        let invoke: Box<dyn Instruction> = match &output {
+            Describe::Result { .. } => Box::new(instructions::LuauProtectedCall { body: invoke }),
+            _ => invoke,
+        };
+
         let body = Box::new(instructions::ImportBlock {
             inputs: parameters.clone(),
             output: output.clone(),
-            body: Box::new(instructions::InvokeLuauFunction {
-                function_name: luau_name.clone(),
-                parameter_count: parameters.len(),
-                result_count: output.value_count().min(1),
-            }),
+            body: invoke,
         });
 
-        import_fns.push(instructions::WasmCreateImport {
-            export_name,
-            parameters,
-            output,
-            body,
-        });
+        import_fns.push((
+            import.rust_name.clone(),
+            instructions::WasmCreateImport {
+                export_name,
+                parameters,
+                output,
+                body,
+            },
+        ));
     }
 
     // Expose the stack pointer, if it exists.
@@ -152,18 +206,39 @@ pub fn build(mut module: Module, out: PathBuf) {
     let mut runtime = fs::File::create(out.join("server/runtime.luau")).expect("file open failed");
     let mut ctx = InstructionContext::new(&mut runtime, &shared_context.intrinsics);
 
-    CreateRuntimeHeader.render(&mut ctx).unwrap();
+    CreateRuntimeHeader { debug_heap }.render(&mut ctx).unwrap();
 
-    for instr in import_fns {
-        instr.render(&mut ctx).expect("render failed");
+    for class in &shared_context.classes {
+        let instr = instructions::WasmCreateClass {
+            rust_name: class.rust_name.clone(),
+            luau_name: class.luau_name.clone(),
+            drop_name: class.drop_name.clone(),
+        };
 
-        debug_assert_eq!(ctx.inputs.len(), 0);
+        instr.render(&mut ctx).expect("render failed");
     }
 
-    for instr in export_fns {
+    for item in &shared_context.enums {
+        let instr = instructions::WasmCreateEnum {
+            rust_name: item.rust_name.clone(),
+            luau_name: item.luau_name.clone(),
+            kind: item.kind,
+            variants: item
+                .variants
+                .iter()
+                .map(|v| (v.luau_name.clone(), v.discriminant))
+                .collect(),
+        };
+
         instr.render(&mut ctx).expect("render failed");
+    }
 
-        debug_assert_eq!(ctx.inputs.len(), 0);
+    for (name, instr) in import_fns {
+        render_function(&mut ctx, &instr, "import", &name);
+    }
+
+    for (name, instr) in export_fns {
+        render_function(&mut ctx, &instr, "export", &name);
     }
 
     CreateRuntimeTail { main_names }.render(&mut ctx).unwrap();
@@ -194,6 +269,21 @@ pub fn build(mut module: Module, out: PathBuf) {
     wasm.flush().expect("flush failed");
     runtime.flush().expect("flush failed");
 
+    /// Types without a conversion are reported with the function they're used in, rather than as a panic.
+    fn render_function(
+        ctx: &mut InstructionContext,
+        instr: &dyn Instruction,
+        kind: &str,
+        name: &str,
+    ) {
+        if let Err(error) = instr.render(ctx) {
+            eprintln!("error: {error}, in {kind} '{name}'");
+            process::exit(1);
+        }
+
+        debug_assert_eq!(ctx.inputs.len(), 0);
+    }
+
     fn write(path: PathBuf, contents: &str) {
         fs::write(path, contents).expect("failed to write file")
     }
@@ -206,6 +296,32 @@ pub fn build(mut module: Module, out: PathBuf) {
         module.imports.iter().find(|v| v.name == name)
     }
 
+    /// Tuples are passed as multiple Luau values, so they can only be returned, not nested in another type.
+    fn check_tuples(args: &[Describe], output: &Describe, kind: &str, name: &str) {
+        let output = match output {
+            Describe::Tuple { items } => items.as_slice(),
+            Describe::Result { ty } => match &**ty {
+                Describe::Tuple { items } => items.as_slice(),
+                ty => std::slice::from_ref(ty),
+            },
+            ty => std::slice::from_ref(ty),
+        };
+
+        if args.iter().chain(output).any(|v| v.contains_tuple()) {
+            panic!("tuples are only supported as the return type, in {kind} '{name}'");
+        }
+    }
+
+    /// The number of values returned by the Luau function, tuples are returned as multiple values.
+    fn luau_result_count(output: &Describe) -> usize {
+        match output {
+            Describe::Void => 0,
+            Describe::Tuple { items } => items.len(),
+            Describe::Result { ty } => luau_result_count(ty),
+            _ => 1,
+        }
+    }
+
     fn interpret_describe(describe_id: Option<FunctionId>, func: &LocalFunction) -> Describe {
         let block = func.block(func.entry_block());
         let mut describe = Vec::new();
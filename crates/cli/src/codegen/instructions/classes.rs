@@ -0,0 +1,56 @@
+use std::io::{self, Write};
+
+use crate::codegen::{
+    macros::{line, pull, push},
+    traits::{Instruction, InstructionContext},
+};
+
+/// Creates the Luau class table for a Rust struct, its methods are added separately as exports.
+pub struct WasmCreateClass {
+    pub rust_name: String,
+    pub luau_name: String,
+    pub drop_name: String,
+}
+
+impl Instruction for WasmCreateClass {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        ctx.vars.scope();
+
+        let rust_name = &self.rust_name;
+        let luau_name = &self.luau_name;
+        let drop_name = &self.drop_name;
+        let class = ctx.vars.next("class");
+        let ptr = ctx.vars.next("ptr");
+
+        push!(ctx, "do");
+        line!(ctx, "local {class} = {{}}");
+        line!(ctx, "{class}.__index = {class}");
+        line!(ctx);
+        push!(ctx, "function {class}:Destroy()");
+        line!(ctx, "local {ptr} = self.__ptr");
+        push!(ctx, "if {ptr} ~= nil then");
+        line!(ctx, "self.__ptr = nil");
+        line!(
+            ctx,
+            "WASM_UNWIND(WASM_STACK.value, pcall(WASM.func_list.{drop_name}, {ptr}))"
+        );
+        pull!(ctx, "end");
+        pull!(ctx, "end");
+        line!(ctx);
+        line!(ctx, "WASM_CLASSES[\"{rust_name}\"] = {class}");
+        line!(ctx, "WASM_EXPORTS[\"{luau_name}\"] = {class}");
+        pull!(ctx, "end");
+
+        ctx.vars.unscope();
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        0
+    }
+
+    fn get_outputs(&self) -> usize {
+        0
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
         macros::{line, pull, push},
         traits::{Instruction, InstructionContext},
     },
-    describe::Describe,
+    describe::{DataType, Describe, Primitive},
 };
 
 /// This instruction exists as a matching utility
@@ -28,19 +28,38 @@ impl Instruction for LuauToRust<'_> {
             | Describe::I32 => Ok(()),
             Describe::ExternRef => LuauExternRefToRust.render(ctx),
             Describe::Boolean => LuauBooleanToRust.render(ctx),
-            Describe::String => LuauStringToRust.render(ctx),
+            Describe::U64 => LuauWideToRust { signed: false }.render(ctx),
+            Describe::I64 => LuauWideToRust { signed: true }.render(ctx),
+            Describe::Char => LuauCharToRust.render(ctx),
+            Describe::String => LuauStringToRust { checked: true }.render(ctx),
+            Describe::Bytes => LuauStringToRust { checked: false }.render(ctx),
+            Describe::Buffer => LuauBufferToRust.render(ctx),
             Describe::Option { ty } => LuauOptionToRust { ty: *ty.clone() }.render(ctx),
+            Describe::Result { ty } => LuauResultToRust { ty }.render(ctx),
+            Describe::Tuple { items } => LuauTupleToRust { items }.render(ctx),
             Describe::Vector { ty } => LuauVecToRust { ty: *ty.clone() }.render(ctx),
+            Describe::Map { key, value } => LuauMapToRust { key, value }.render(ctx),
+            Describe::Class { name } => LuauClassToRust { name, owned: true }.render(ctx),
+            Describe::Enum { name } => LuauEnumToRust { name }.render(ctx),
+            Describe::EnumItem => LuauEnumItemToRust.render(ctx),
+            Describe::DataType { ty } => LuauDataTypeToRust { ty: *ty }.render(ctx),
+            Describe::Table { name, fields } => LuauTableToRust { name, fields }.render(ctx),
+            Describe::Ref { ty } => LuauRefToRust { ty }.render(ctx),
+            Describe::RefMut { ty } => LuauRefMutToRust { ty }.render(ctx),
             Describe::Void => {
                 ctx.pop();
                 Ok(())
             }
-            _ => unimplemented!(),
+            ty => Err(super::unsupported("a value received from luau", ty)),
         }
     }
 
     fn get_inputs(&self) -> usize {
-        1
+        match self.ty {
+            Describe::Tuple { items } => items.len(),
+            Describe::Result { ty } => 1 + LuauToRust { ty }.get_inputs(),
+            _ => 1,
+        }
     }
 
     fn get_outputs(&self) -> usize {
@@ -67,11 +86,148 @@ impl Instruction for LuauBooleanToRust {
     }
 }
 
-pub struct LuauStringToRust;
+/// Splits a number into the halves of a 64-bit integer, erroring if it isn't an integer in range.
+pub struct LuauWideToRust {
+    signed: bool,
+}
+
+impl Instruction for LuauWideToRust {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let value = ctx.pop();
+        let [low, high] = [ctx.vars.next("low"), ctx.vars.next("high")];
+
+        line!(
+            ctx,
+            "local {low}, {high} = WASM_SPLIT_WIDE({value}, {})",
+            self.signed
+        );
+
+        ctx.push(low);
+        ctx.push(high);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        2
+    }
+}
+
+pub struct LuauCharToRust;
+
+impl Instruction for LuauCharToRust {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let value = ctx.pop();
+        ctx.push(format!("WASM_TO_CHAR({value})"));
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+/// Reads the number of a Roblox `EnumItem`, erroring if the value isn't one.
+pub struct LuauEnumItemToRust;
+
+impl Instruction for LuauEnumItemToRust {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let value = ctx.pop();
+        ctx.push(format!("WASM_FROM_ENUM_ITEM({value})"));
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+/// Reads the components out of a Roblox data type, erroring if the value isn't one.
+///
+/// A `CFrame` has too many components to pass directly, so they're written into newly allocated memory instead.
+pub struct LuauDataTypeToRust {
+    ty: DataType,
+}
+
+impl Instruction for LuauDataTypeToRust {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let name = self.ty.name();
+        let value = ctx.pop();
+        let data = ctx.vars.next("data");
+
+        line!(
+            ctx,
+            "local {data} = WASM_EXPECT_TYPEOF({value}, \"{name}\")"
+        );
+
+        if !self.ty.is_boxed() {
+            for field in self.ty.component_fields() {
+                ctx.push(format!("{data}.{field}"));
+            }
+
+            return Ok(());
+        }
+
+        let primitives = &self.ty.components();
+        let components = ctx.vars.many(primitives.len(), "component");
+        let ptr = ctx.vars.next("ptr");
+        let alloc = ctx.intrinsics.get("alloc");
+        let size = Primitive::memory_size(primitives);
+        let align = Primitive::max_align(primitives);
+
+        line!(
+            ctx,
+            "local {} = {data}:GetComponents()",
+            components.join(", ")
+        );
+        line!(ctx, "local {ptr} = {alloc}({size}, {align})");
+
+        for component in components {
+            ctx.push(component);
+        }
+
+        ctx.push(&ptr);
+        WriteMemory { primitives }.render(ctx)?;
+
+        ctx.push(ptr);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        Describe::DataType { ty: self.ty }.value_count()
+    }
+}
+
+/// Copies a string into memory, checked strings raise an error if they aren't valid UTF-8.
+pub struct LuauStringToRust {
+    checked: bool,
+}
 
 impl Instruction for LuauStringToRust {
     fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
-        let value = ctx.pop_complex()?;
+        let mut value = ctx.pop();
+        if self.checked {
+            value = format!("WASM_TO_UTF8({value})");
+        }
+
+        let value = ctx.prereq_complex(value)?;
         let result = ctx.vars.next("string");
         let alloc = ctx.intrinsics.get("alloc");
 
@@ -93,6 +249,38 @@ impl Instruction for LuauStringToRust {
     }
 }
 
+/// Copies the contents of a Luau buffer into newly allocated memory in a single operation.
+pub struct LuauBufferToRust;
+
+impl Instruction for LuauBufferToRust {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let value = ctx.pop_complex()?;
+        let len = ctx.vars.next("len");
+        let result = ctx.vars.next("buffer");
+        let alloc = ctx.intrinsics.get("alloc");
+
+        push!(ctx, "if type({value}) ~= \"buffer\" then");
+        line!(ctx, "error(`expected buffer, got {{typeof({value})}}`, 2)");
+        pull!(ctx, "end");
+        line!(ctx, "local {len} = buffer.len({value})");
+        line!(ctx, "local {result} = {alloc}({len}, 1)");
+        line!(ctx, "buffer.copy(MEMORY.data, {result}, {value}, 0, {len})");
+
+        ctx.push(result);
+        ctx.push(len);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        2
+    }
+}
+
 pub struct LuauVecToRust {
     ty: Describe,
 }
@@ -134,6 +322,59 @@ impl Instruction for LuauVecToRust {
     }
 }
 
+/// Writes the key-value pairs of a dictionary into memory, iterating over it with `pairs`.
+pub struct LuauMapToRust<'a> {
+    key: &'a Describe,
+    value: &'a Describe,
+}
+
+impl Instruction for LuauMapToRust<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let map = ctx.pop_complex()?;
+        let primitives = &[self.key.primitive_values(), self.value.primitive_values()].concat();
+        let size = Primitive::memory_size(primitives);
+        let align = Primitive::max_align(primitives);
+        let alloc = ctx.intrinsics.get("alloc");
+        let var = ctx.vars.next("map");
+        let count = ctx.vars.next("count");
+        let index = ctx.vars.next("index");
+        let key = ctx.vars.next("key");
+        let value = ctx.vars.next("value");
+
+        line!(ctx, "local {count} = 0");
+        push!(ctx, "for _ in pairs({map}) do");
+        line!(ctx, "{count} += 1");
+        pull!(ctx, "end");
+        line!(ctx, "local {var} = {alloc}({count} * {size}, {align})");
+        line!(ctx, "local {index} = 0");
+        push!(ctx, "for {key}, {value} in pairs({map}) do");
+
+        ctx.push(key);
+        LuauToRust { ty: self.key }.render(ctx)?;
+        ctx.push(value);
+        LuauToRust { ty: self.value }.render(ctx)?;
+
+        ctx.push(format!("{var} + {index} * {size}"));
+        WriteMemory { primitives }.render(ctx)?;
+
+        line!(ctx, "{index} += 1");
+        pull!(ctx, "end");
+
+        ctx.push(var);
+        ctx.push(count);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        2
+    }
+}
+
 pub struct LuauOptionToRust {
     ty: Describe,
 }
@@ -176,6 +417,97 @@ impl Instruction for LuauOptionToRust {
     }
 }
 
+/// Converts the outputs of a `LuauProtectedCall`, errors are sent as a pointer to the boxed message slice.
+pub struct LuauResultToRust<'a> {
+    ty: &'a Describe,
+}
+
+impl Instruction for LuauResultToRust<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let converter = LuauToRust { ty: self.ty };
+        let values = ctx.pop_many(converter.get_inputs());
+        let success = ctx.pop();
+        let error_name = ctx.vars.next("error");
+        let output_names = ctx.vars.many(self.ty.value_count(), "result");
+        let alloc = ctx.intrinsics.get("alloc");
+
+        for name in [&error_name].into_iter().chain(output_names.iter()) {
+            line!(ctx, "local {name} = 0");
+        }
+
+        if !output_names.is_empty() {
+            push!(ctx, "if {success} then");
+
+            ctx.inputs.extend(values.iter().cloned());
+            converter.render(ctx)?;
+
+            let output_exprs = ctx.pop_many(self.ty.value_count());
+            for (output_name, value) in output_names.iter().zip(output_exprs.iter()) {
+                line!(ctx, "{output_name} = {value}");
+            }
+
+            pull!(ctx, "end");
+        }
+
+        push!(ctx, "if not {success} then");
+
+        ctx.push(format!("tostring({})", values[0]));
+        LuauStringToRust { checked: false }.render(ctx)?;
+
+        line!(ctx, "{error_name} = {alloc}(8, 4)");
+        ctx.push(&error_name);
+        WriteMemory {
+            primitives: &[Primitive::U32, Primitive::U32],
+        }
+        .render(ctx)?;
+
+        pull!(ctx, "end");
+
+        ctx.push(error_name);
+        ctx.inputs.extend(output_names);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1 + LuauToRust { ty: self.ty }.get_inputs()
+    }
+
+    fn get_outputs(&self) -> usize {
+        1 + self.ty.value_count()
+    }
+}
+
+/// Converts multiple Luau values into the items of a tuple.
+pub struct LuauTupleToRust<'a> {
+    items: &'a [Describe],
+}
+
+impl Instruction for LuauTupleToRust<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let values = ctx.pop_many(self.items.len());
+        let mut outputs = Vec::new();
+
+        for (ty, value) in self.items.iter().zip(values) {
+            ctx.push(value);
+            LuauToRust { ty }.render(ctx)?;
+            outputs.extend(ctx.pop_many(ty.value_count()));
+        }
+
+        ctx.inputs.extend(outputs);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        self.items.len()
+    }
+
+    fn get_outputs(&self) -> usize {
+        self.items.iter().map(|v| v.value_count()).sum()
+    }
+}
+
 pub struct LuauExternRefToRust;
 
 impl Instruction for LuauExternRefToRust {
@@ -183,9 +515,7 @@ impl Instruction for LuauExternRefToRust {
         let heap = ctx.vars.next("heap");
         let value = ctx.pop();
 
-        line!(ctx, "HEAP_ID += 1");
-        line!(ctx, "local {heap} = HEAP_ID");
-        line!(ctx, "HEAP[HEAP_ID] = {value}");
+        line!(ctx, "local {heap} = WASM_HEAP_ALLOC({value})");
 
         ctx.push(heap);
 
@@ -200,3 +530,251 @@ impl Instruction for LuauExternRefToRust {
         1
     }
 }
+
+/// Removes the temporary `HEAP` entry of a borrowed value.
+pub struct LuauReleaseExternRef;
+
+impl Instruction for LuauReleaseExternRef {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let heap = ctx.pop();
+
+        line!(ctx, "WASM_HEAP_DROP({heap})");
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        0
+    }
+}
+
+pub struct LuauRefToRust<'a> {
+    ty: &'a Describe,
+}
+
+impl Instruction for LuauRefToRust<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        match &self.ty {
+            Describe::Class { name } => LuauClassToRust { name, owned: false }.render(ctx),
+            // Borrowed strings and slices are copied into memory, which Rust frees once the call has finished.
+            Describe::String => LuauStringToRust { checked: true }.render(ctx),
+            Describe::Slice { ty } => LuauVecToRust { ty: *ty.clone() }.render(ctx),
+            Describe::ExternRef => LuauExternRefToRust.render(ctx),
+            ty => Err(super::unsupported("a reference received from luau", ty)),
+        }
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        self.ty.value_count()
+    }
+}
+
+pub struct LuauRefMutToRust<'a> {
+    ty: &'a Describe,
+}
+
+impl Instruction for LuauRefMutToRust<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        match &self.ty {
+            Describe::Class { name } => LuauClassToRust { name, owned: false }.render(ctx),
+            Describe::Slice { ty } => LuauVecToRust { ty: *ty.clone() }.render(ctx),
+            Describe::Vector { ty } => {
+                LuauVecToRust { ty: *ty.clone() }.render(ctx)?;
+
+                // The vector is lent through a header, which Rust overwrites if the vector is changed.
+                let [addr, len] = ctx.pop_array();
+                let header = ctx.vars.next("header");
+                let alloc = ctx.intrinsics.get("alloc");
+
+                line!(ctx, "local {header} = {alloc}(8, 4)");
+                line!(ctx, "buffer.writeu32(MEMORY.data, {header}, {addr})");
+                line!(ctx, "buffer.writeu32(MEMORY.data, {header} + 4, {len})");
+
+                ctx.push(header);
+
+                Ok(())
+            }
+            ty => Err(super::unsupported(
+                "a mutable reference received from luau",
+                ty,
+            )),
+        }
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        Describe::RefMut {
+            ty: Box::new(self.ty.clone()),
+        }
+        .value_count()
+    }
+}
+
+/// Writes the elements of a Luau table back into a mutably borrowed slice, once the Luau function has finished.
+pub struct LuauWriteBackToRust<'a> {
+    pub ty: &'a Describe,
+}
+
+impl Instruction for LuauWriteBackToRust<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let Describe::RefMut { ty } = self.ty else {
+            return Err(super::unsupported("a value written back to rust", self.ty));
+        };
+
+        let Describe::Slice { ty } = &**ty else {
+            return Err(super::unsupported("a value written back to rust", ty));
+        };
+
+        let [table, addr, len] = ctx.pop_array();
+        let size = ty.memory_size();
+        let primitives = &ty.primitive_values();
+        let index = ctx.vars.next("index");
+
+        // The length of a slice is fixed, so only the original elements are written back.
+        push!(ctx, "for {index} = 1, {len} do");
+
+        ctx.push(format!("{table}[{index}]"));
+        LuauToRust { ty }.render(ctx)?;
+
+        ctx.push(format!("{addr} + ({index} - 1) * {size}"));
+        WriteMemory { primitives }.render(ctx)?;
+
+        pull!(ctx, "end");
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1 + self.ty.value_count()
+    }
+
+    fn get_outputs(&self) -> usize {
+        0
+    }
+}
+
+/// Fetches the pointer out of a class instance, owned values are detached from the instance.
+pub struct LuauClassToRust<'a> {
+    name: &'a str,
+    owned: bool,
+}
+
+impl Instruction for LuauClassToRust<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let name = self.name;
+        let value = ctx.pop_complex()?;
+        let ptr = ctx.vars.next("ptr");
+
+        push!(
+            ctx,
+            "if getmetatable({value}) ~= WASM_CLASSES[\"{name}\"] or {value}.__ptr == nil then"
+        );
+        line!(ctx, "error(\"expected a valid {name}\", 2)");
+        pull!(ctx, "end");
+        line!(ctx, "local {ptr} = {value}.__ptr");
+
+        if self.owned {
+            line!(ctx, "{value}.__ptr = nil");
+        }
+
+        ctx.push(ptr);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+/// Looks up the variant index of an enum value, erroring if it isn't a variant of the enum.
+pub struct LuauEnumToRust<'a> {
+    name: &'a str,
+}
+
+impl Instruction for LuauEnumToRust<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let name = self.name;
+        let value = ctx.pop();
+        let index = ctx.vars.next("index");
+
+        line!(
+            ctx,
+            "local {index} = WASM_ENUMS[\"{name}\"].indices[{value}]"
+        );
+        push!(ctx, "if {index} == nil then");
+        line!(ctx, "error(\"expected a valid {name}\", 2)");
+        pull!(ctx, "end");
+
+        ctx.push(index);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+/// Writes the fields of a table into newly allocated memory, which Rust takes ownership of.
+pub struct LuauTableToRust<'a> {
+    name: &'a str,
+    fields: &'a [(String, Describe)],
+}
+
+impl Instruction for LuauTableToRust<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let name = self.name;
+        let value = ctx.pop_complex()?;
+        let table = ctx.vars.next("table");
+        let alloc = ctx.intrinsics.get("alloc");
+        let primitives = &Describe::field_primitives(self.fields);
+        let size = Primitive::memory_size(primitives);
+        let align = Primitive::max_align(primitives);
+
+        push!(ctx, "if type({value}) ~= \"table\" then");
+        line!(ctx, "error(\"expected a valid {name}\", 2)");
+        pull!(ctx, "end");
+
+        for (field, ty) in self.fields {
+            ctx.push(format!("{value}[\"{field}\"]"));
+            LuauToRust { ty }.render(ctx)?;
+        }
+
+        line!(ctx, "local {table} = {alloc}({size}, {align})");
+
+        ctx.push(&table);
+        WriteMemory { primitives }.render(ctx)?;
+
+        ctx.push(table);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
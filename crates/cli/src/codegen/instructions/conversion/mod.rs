@@ -3,3 +3,15 @@ pub mod rust_to_lua;
 
 pub use luau_to_rust::*;
 pub use rust_to_lua::*;
+
+use std::io;
+
+use crate::describe::Describe;
+
+/// An error for a type which has no conversion, the build reports it with the function the type is used in.
+fn unsupported(kind: &str, ty: &Describe) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("{kind} of type {ty:?} is not supported"),
+    )
+}
@@ -2,11 +2,11 @@ use std::io::{self, Write};
 
 use crate::{
     codegen::{
-        instructions::{PullMemory, PushConst},
-        macros::{line, pull, push},
+        instructions::{ExportBlock, InvokeRustFunction, PullMemory, PushConst},
+        macros::{line, list, pull, push, text},
         traits::{Instruction, InstructionContext},
     },
-    describe::Describe,
+    describe::{DataType, Describe, Primitive},
 };
 
 /// This instruction exists as a matching utility
@@ -26,14 +26,27 @@ impl Instruction for RustToLuau<'_> {
             | Describe::I8
             | Describe::I16
             | Describe::I32 => Ok(()),
+            // Roblox converts numbers into the enum item when they're assigned or passed to a method.
+            Describe::EnumItem => Ok(()),
             Describe::ExternRef => RustOwnedExternRefToLuau.render(ctx),
             Describe::Boolean => RustBooleanToLuau.render(ctx),
+            Describe::U64 | Describe::I64 => RustWideToLuau.render(ctx),
+            Describe::Char => RustCharToLuau.render(ctx),
             Describe::Option { ty } => RustOptionToLuau { ty: *ty.clone() }.render(ctx),
+            Describe::Result { ty } => RustResultToLuau { ty }.render(ctx),
+            Describe::Tuple { items } => RustTupleToLuau { items }.render(ctx),
             Describe::Vector { ty } => RustVectorToLuau { ty: *ty.clone() }.render(ctx),
+            Describe::Map { key, value } => RustMapToLuau { key, value }.render(ctx),
+            Describe::Variadic { ty } => RustVariadicToLuau { ty: *ty.clone() }.render(ctx),
             Describe::Void => PushConst::new("nil").render(ctx),
-            Describe::String => RustOwnedStringToLuau.render(ctx),
-            Describe::Ref { ty } => RustRefToLuau { ty }.render(ctx),
-            _ => unimplemented!(),
+            Describe::String | Describe::Bytes => RustOwnedStringToLuau.render(ctx),
+            Describe::Buffer => RustBufferToLuau { owned: true }.render(ctx),
+            Describe::Ref { ty } | Describe::RefMut { ty } => RustRefToLuau { ty }.render(ctx),
+            Describe::Class { name } => RustClassToLuau { name }.render(ctx),
+            Describe::Enum { name } => RustEnumToLuau { name }.render(ctx),
+            Describe::DataType { ty } => RustDataTypeToLuau { ty: *ty }.render(ctx),
+            Describe::Table { fields, .. } => RustTableToLuau { fields }.render(ctx),
+            ty => Err(super::unsupported("a value sent to luau", ty)),
         }
     }
 
@@ -42,10 +55,15 @@ impl Instruction for RustToLuau<'_> {
     }
 
     fn get_outputs(&self) -> usize {
-        1
+        match self.ty {
+            Describe::Tuple { items } => items.len(),
+            Describe::Result { ty } => RustToLuau { ty }.get_outputs(),
+            _ => 1,
+        }
     }
 }
 
+/// Converts a borrowed value, which is read without freeing anything it owns.
 pub struct RustRefToLuau<'a> {
     ty: &'a Describe,
 }
@@ -53,12 +71,58 @@ pub struct RustRefToLuau<'a> {
 impl Instruction for RustRefToLuau<'_> {
     fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
         match &self.ty {
-            Describe::String => RustRefStringToLuau.render(ctx),
-            Describe::Slice { ty } => RustSliceToLuau { ty: *ty.clone() }.render(ctx),
+            Describe::String | Describe::Bytes => RustRefStringToLuau.render(ctx),
+            Describe::Buffer => RustBufferToLuau { owned: false }.render(ctx),
+            Describe::Slice { ty } | Describe::Vector { ty } => {
+                RustSliceToLuau { ty: *ty.clone() }.render(ctx)
+            }
             Describe::ExternRef => RustRefExternRefToLuau.render(ctx),
-            ty => {
-                unimplemented!("invalid rust reference type: {ty:?}");
+            Describe::Function { args, return_type } => {
+                RustClosureToLuau { args, return_type }.render(ctx)
             }
+            Describe::Ref { ty } => RustRefToLuau { ty }.render(ctx),
+            Describe::Option { ty } => RustOptionToLuau {
+                ty: Describe::Ref { ty: ty.clone() },
+            }
+            .render(ctx),
+            Describe::Map { key, value } => RustMapToLuau {
+                key: &Describe::Ref { ty: key.clone() },
+                value: &Describe::Ref { ty: value.clone() },
+            }
+            .render(ctx),
+            Describe::Table { fields, .. } => {
+                let fields: Vec<_> = fields
+                    .iter()
+                    .map(|(name, ty)| {
+                        (
+                            name.clone(),
+                            Describe::Ref {
+                                ty: Box::new(ty.clone()),
+                            },
+                        )
+                    })
+                    .collect();
+
+                RustTableToLuau { fields: &fields }.render(ctx)
+            }
+            // Values which don't own anything are converted the same way as owned values.
+            Describe::F32
+            | Describe::F64
+            | Describe::U8
+            | Describe::U16
+            | Describe::U32
+            | Describe::I8
+            | Describe::I16
+            | Describe::I32
+            | Describe::U64
+            | Describe::I64
+            | Describe::Boolean
+            | Describe::Char
+            | Describe::Enum { .. }
+            | Describe::EnumItem => RustToLuau { ty: self.ty }.render(ctx),
+            // Borrowed data types are copied by Rust, so they're converted the same way as owned values.
+            Describe::DataType { .. } => RustToLuau { ty: self.ty }.render(ctx),
+            ty => Err(super::unsupported("a reference sent to luau", ty)),
         }
     }
 
@@ -71,26 +135,41 @@ impl Instruction for RustRefToLuau<'_> {
     }
 }
 
+/// Reads the borrowed elements of a slice into a table.
+///
+/// Slices of primitives and data types are lent by Rust, but other slices are copied into a temporary array which is freed afterwards.
 pub struct RustSliceToLuau {
     ty: Describe,
 }
 
 impl Instruction for RustSliceToLuau {
     fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
-        // currently only WasmPrimitive slices are accepted
         let [addr, len] = ctx.pop_array();
+        let addr = ctx.prereq_complex(addr)?;
+        let len = ctx.prereq_complex(len)?;
         let result_name = ctx.vars.next("slice");
+        let size = self.ty.memory_size();
+        let align = self.ty.max_align();
+        let primitives = &self.ty.primitive_values();
         let index = ctx.vars.next("index");
-        let primitive = self.ty.primitive_values()[0];
-        let buffer = primitive.buffer_name();
-        let size = primitive.byte_size();
-        let len = ctx.prereq_complex(len)?;
 
         line!(ctx, "local {result_name} = table.create({len})");
         push!(ctx, "for {index} = 1, {len} do");
-        line!(ctx, "table.insert({result_name}, buffer.read{buffer}(MEMORY.data, {addr} + ({index} - 1) * {size}))");
+
+        ctx.push(format!("{addr} + ({index} - 1) * {size}"));
+        PullMemory { primitives }.render(ctx)?;
+
+        RustRefToLuau { ty: &self.ty }.render(ctx)?;
+
+        let value = ctx.pop();
+        line!(ctx, "{result_name}[{index}] = {value}");
         pull!(ctx, "end");
 
+        if !self.ty.is_lent() {
+            let free = ctx.intrinsics.get("free");
+            line!(ctx, "{free}({addr}, {len} * {size}, {align})");
+        }
+
         ctx.push(result_name);
 
         Ok(())
@@ -130,7 +209,7 @@ impl Instruction for RustVectorToLuau {
         RustToLuau { ty: &self.ty }.render(ctx)?;
 
         let value = ctx.pop();
-        line!(ctx, "table.insert({result_name}, {value})");
+        line!(ctx, "{result_name}[{index}] = {value}");
         pull!(ctx, "end");
         line!(ctx, "{free}({addr}, {len} * {size}, {align})");
 
@@ -148,6 +227,159 @@ impl Instruction for RustVectorToLuau {
     }
 }
 
+/// Reads the elements of a mutably borrowed list back into the original Luau table, once the Rust function has finished.
+pub struct RustWriteBackToLuau<'a> {
+    pub ty: &'a Describe,
+}
+
+impl Instruction for RustWriteBackToLuau<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let Describe::RefMut { ty } = self.ty else {
+            return Err(super::unsupported("a value written back to luau", self.ty));
+        };
+
+        let (table, addr, len, ty) = match &**ty {
+            Describe::Slice { ty } => {
+                let [table, addr, len] = ctx.pop_array();
+                (table, addr, ctx.prereq_complex(len)?, ty)
+            }
+            Describe::Vector { ty } => {
+                // Vectors can be resized, so their new pointer and length are read out of the header.
+                let [table, header] = ctx.pop_array();
+                let addr = ctx.vars.next("addr");
+                let len = ctx.vars.next("len");
+                let free = ctx.intrinsics.get("free");
+
+                line!(ctx, "local {addr} = buffer.readu32(MEMORY.data, {header})");
+                line!(
+                    ctx,
+                    "local {len} = buffer.readu32(MEMORY.data, {header} + 4)"
+                );
+                line!(ctx, "{free}({header}, 8, 4)");
+
+                let index = ctx.vars.next("index");
+                push!(ctx, "for {index} = #{table}, {len} + 1, -1 do");
+                line!(ctx, "{table}[{index}] = nil");
+                pull!(ctx, "end");
+
+                (table, addr, len, ty)
+            }
+            ty => return Err(super::unsupported("a value written back to luau", ty)),
+        };
+
+        let free = ctx.intrinsics.get("free");
+        let size = ty.memory_size();
+        let align = ty.max_align();
+        let primitives = &ty.primitive_values();
+        let index = ctx.vars.next("index");
+
+        push!(ctx, "for {index} = 1, {len} do");
+
+        ctx.push(format!("{addr} + ({index} - 1) * {size}"));
+        PullMemory { primitives }.render(ctx)?;
+
+        RustToLuau { ty }.render(ctx)?;
+
+        let value = ctx.pop();
+        line!(ctx, "{table}[{index}] = {value}");
+        pull!(ctx, "end");
+        line!(ctx, "{free}({addr}, {len} * {size}, {align})");
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1 + self.ty.value_count()
+    }
+
+    fn get_outputs(&self) -> usize {
+        0
+    }
+}
+
+/// Reads the key-value pairs of a map out of memory into a dictionary, and frees the memory afterwards.
+pub struct RustMapToLuau<'a> {
+    key: &'a Describe,
+    value: &'a Describe,
+}
+
+impl Instruction for RustMapToLuau<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let [addr, len] = ctx.pop_array();
+        let addr = ctx.prereq_complex(addr)?;
+        let len = ctx.prereq_complex(len)?;
+        let result_name = ctx.vars.next("map");
+        let free = ctx.intrinsics.get("free");
+        let primitives = &[self.key.primitive_values(), self.value.primitive_values()].concat();
+        let size = Primitive::memory_size(primitives);
+        let align = Primitive::max_align(primitives);
+        let index = ctx.vars.next("index");
+
+        line!(ctx, "local {result_name} = {{}}");
+        push!(ctx, "for {index} = 1, {len} do");
+
+        ctx.push(format!("{addr} + ({index} - 1) * {size}"));
+        PullMemory { primitives }.render(ctx)?;
+
+        let value_prims = ctx.pop_many(self.value.value_count());
+        RustToLuau { ty: self.key }.render(ctx)?;
+        let key = ctx.pop_complex()?;
+
+        ctx.inputs.extend(value_prims);
+        RustToLuau { ty: self.value }.render(ctx)?;
+        let value = ctx.pop();
+
+        line!(ctx, "{result_name}[{key}] = {value}");
+        pull!(ctx, "end");
+        line!(ctx, "{free}({addr}, {len} * {size}, {align})");
+
+        ctx.push(result_name);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        2
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+/// Unpacks the values of a vector, this is only valid as the last argument of a call.
+pub struct RustVariadicToLuau {
+    ty: Describe,
+}
+
+impl Instruction for RustVariadicToLuau {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let [addr, len] = ctx.pop_array();
+        let len = ctx.prereq_complex(len)?;
+        ctx.push(addr);
+        ctx.push(&len);
+
+        RustVectorToLuau {
+            ty: self.ty.clone(),
+        }
+        .render(ctx)?;
+
+        // The length is passed explicitly, so nil values don't cut the arguments short.
+        let vector = ctx.pop();
+        ctx.push(format!("table.unpack({vector}, 1, {len})"));
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        2
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
 pub struct RustRefStringToLuau;
 
 impl Instruction for RustRefStringToLuau {
@@ -200,6 +432,40 @@ impl Instruction for RustOwnedStringToLuau {
     }
 }
 
+/// Copies the bytes into a new Luau buffer in a single operation, owned bytes are freed afterwards.
+pub struct RustBufferToLuau {
+    owned: bool,
+}
+
+impl Instruction for RustBufferToLuau {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let [addr, len] = ctx.pop_array();
+        let addr = ctx.prereq_complex(addr)?;
+        let len = ctx.prereq_complex(len)?;
+        let var = ctx.vars.next("buffer");
+        let free = ctx.intrinsics.get("free");
+
+        line!(ctx, "local {var} = buffer.create({len})");
+        line!(ctx, "buffer.copy({var}, 0, MEMORY.data, {addr}, {len})");
+
+        if self.owned {
+            line!(ctx, "{free}({addr}, {len}, 1)");
+        }
+
+        ctx.push(var);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        2
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
 pub struct RustBooleanToLuau;
 
 impl Instruction for RustBooleanToLuau {
@@ -218,6 +484,43 @@ impl Instruction for RustBooleanToLuau {
     }
 }
 
+/// Joins the halves of a 64-bit integer, erroring if the number can't represent it exactly.
+pub struct RustWideToLuau;
+
+impl Instruction for RustWideToLuau {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let [low, high] = ctx.pop_array();
+        ctx.push(format!("WASM_JOIN_WIDE({low}, {high})"));
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        2
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+pub struct RustCharToLuau;
+
+impl Instruction for RustCharToLuau {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let value = ctx.pop();
+        ctx.push(format!("utf8.char({value})"));
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
 pub struct RustOptionToLuau {
     ty: Describe,
 }
@@ -251,6 +554,85 @@ impl Instruction for RustOptionToLuau {
     }
 }
 
+/// Raises the error message with `error` when the result is an error, otherwise converts the value.
+pub struct RustResultToLuau<'a> {
+    ty: &'a Describe,
+}
+
+impl Instruction for RustResultToLuau<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let error = ctx.inputs[ctx.inputs.len() - self.get_inputs()].clone();
+        let free = ctx.intrinsics.get("free");
+
+        push!(ctx, "if {error} ~= 0 then");
+
+        // The error is a pointer to the boxed message slice.
+        ctx.push(&error);
+        PullMemory {
+            primitives: &[Primitive::U32, Primitive::U32],
+        }
+        .render(ctx)?;
+        RustOwnedStringToLuau.render(ctx)?;
+
+        let message = ctx.pop();
+        line!(ctx, "{free}({error}, 8, 4)");
+        line!(ctx, "error({message}, 2)");
+        pull!(ctx, "end");
+
+        let converter = RustToLuau { ty: self.ty };
+        converter.render(ctx)?;
+
+        // Pop the error off, since we couldn't pop it earlier.
+        let values = ctx.pop_many(converter.get_outputs());
+        ctx.pop();
+        ctx.inputs.extend(values);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1 + self.ty.value_count()
+    }
+
+    fn get_outputs(&self) -> usize {
+        RustToLuau { ty: self.ty }.get_outputs()
+    }
+}
+
+/// Converts each item of a tuple, which become multiple Luau values.
+pub struct RustTupleToLuau<'a> {
+    items: &'a [Describe],
+}
+
+impl Instruction for RustTupleToLuau<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let mut inputs = Vec::new();
+        for ty in self.items.iter().rev() {
+            inputs.push((ty, ctx.pop_many(ty.value_count())));
+        }
+
+        let mut values = Vec::new();
+        for (ty, names) in inputs.into_iter().rev() {
+            let converter = RustToLuau { ty };
+            ctx.inputs.extend(names);
+            converter.render(ctx)?;
+            values.extend(ctx.pop_many(converter.get_outputs()));
+        }
+
+        ctx.inputs.extend(values);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        self.items.iter().map(|v| v.value_count()).sum()
+    }
+
+    fn get_outputs(&self) -> usize {
+        self.items.len()
+    }
+}
+
 pub struct RustOwnedExternRefToLuau;
 
 impl Instruction for RustOwnedExternRefToLuau {
@@ -258,8 +640,7 @@ impl Instruction for RustOwnedExternRefToLuau {
         let value = ctx.pop_complex()?;
         let value_name = ctx.vars.next("value");
 
-        line!(ctx, "local {value_name} = HEAP[{value}]");
-        line!(ctx, "HEAP[{value}] = nil");
+        line!(ctx, "local {value_name} = WASM_HEAP_TAKE({value})");
 
         ctx.push(value_name);
 
@@ -281,7 +662,145 @@ impl Instruction for RustRefExternRefToLuau {
     fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
         let value = ctx.pop();
 
-        ctx.push(format!("HEAP[{value}]"));
+        ctx.push(format!("WASM_HEAP_GET({value})"));
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+pub struct RustClassToLuau<'a> {
+    name: &'a str,
+}
+
+impl Instruction for RustClassToLuau<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let name = self.name;
+        let ptr = ctx.pop();
+
+        ctx.push(format!(
+            "setmetatable({{ __ptr = {ptr} }}, WASM_CLASSES[\"{name}\"])"
+        ));
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+pub struct RustEnumToLuau<'a> {
+    name: &'a str,
+}
+
+impl Instruction for RustEnumToLuau<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let name = self.name;
+        let index = ctx.pop();
+
+        ctx.push(format!("WASM_ENUMS[\"{name}\"].values[{index} + 1]"));
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+/// Constructs a Roblox data type out of its components.
+///
+/// A `CFrame` is passed as a pointer, so its components are read out of memory and the memory is freed afterwards.
+pub struct RustDataTypeToLuau {
+    ty: DataType,
+}
+
+impl Instruction for RustDataTypeToLuau {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let name = self.ty.name();
+        let primitives = &self.ty.components();
+
+        if !self.ty.is_boxed() {
+            let components = ctx.pop_many(primitives.len());
+            ctx.push(format!("{name}.new({})", components.join(", ")));
+
+            return Ok(());
+        }
+
+        let ptr = ctx.pop_complex()?;
+        let data = ctx.vars.next("data");
+        let free = ctx.intrinsics.get("free");
+        let size = Primitive::memory_size(primitives);
+        let align = Primitive::max_align(primitives);
+
+        ctx.push(&ptr);
+        PullMemory { primitives }.render(ctx)?;
+
+        let components = ctx.pop_many(primitives.len());
+        line!(ctx, "local {data} = {name}.new({})", components.join(", "));
+        line!(ctx, "{free}({ptr}, {size}, {align})");
+
+        ctx.push(data);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        Describe::DataType { ty: self.ty }.value_count()
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+/// Reads the fields of a table out of memory, and frees the memory afterwards.
+pub struct RustTableToLuau<'a> {
+    fields: &'a [(String, Describe)],
+}
+
+impl Instruction for RustTableToLuau<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let ptr = ctx.pop_complex()?;
+        let table = ctx.vars.next("table");
+        let free = ctx.intrinsics.get("free");
+        let primitives = &Describe::field_primitives(self.fields);
+        let size = Primitive::memory_size(primitives);
+        let align = Primitive::max_align(primitives);
+
+        line!(ctx, "local {table} = {{}}");
+
+        ctx.push(&ptr);
+        PullMemory { primitives }.render(ctx)?;
+
+        let mut values = ctx.pop_many(primitives.len()).into_iter();
+        for (name, ty) in self.fields {
+            ctx.inputs.extend(values.by_ref().take(ty.value_count()));
+            RustToLuau { ty }.render(ctx)?;
+
+            let value = ctx.pop();
+            line!(ctx, "{table}[\"{name}\"] = {value}");
+        }
+
+        line!(ctx, "{free}({ptr}, {size}, {align})");
+
+        ctx.push(table);
 
         Ok(())
     }
@@ -294,3 +813,73 @@ impl Instruction for RustRefExternRefToLuau {
         1
     }
 }
+
+/// Creates a Luau function which invokes a Rust closure, the function is reused until the closure is dropped.
+pub struct RustClosureToLuau<'a> {
+    args: &'a [Describe],
+    return_type: &'a Describe,
+}
+
+impl Instruction for RustClosureToLuau<'_> {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let [invoke, data] = ctx.pop_array();
+        let invoke = ctx.prereq_complex(invoke)?;
+        let data = ctx.prereq_complex(data)?;
+        let closure = ctx.vars.next("closure");
+
+        line!(ctx, "local {closure} = WASM_CLOSURES[{data}]");
+        push!(ctx, "if {closure} == nil then");
+
+        ctx.vars.scope();
+
+        let parameters = ctx.vars.many(self.args.len(), "param");
+        text!(ctx, "{closure} = function(");
+        list!(ctx, parameters);
+        push!(ctx, ")");
+        push!(ctx, "if WASM_CLOSURES[{data}] ~= {closure} then");
+        line!(ctx, "error(\"attempt to call a dropped closure\", 2)");
+        pull!(ctx, "end");
+
+        // The closure data pointer is passed as the first argument to the invoke shim.
+        let inputs = [Describe::U32]
+            .into_iter()
+            .chain(self.args.iter().cloned())
+            .collect::<Vec<_>>();
+
+        ctx.push(&data);
+        ctx.inputs.extend(parameters);
+
+        let block = ExportBlock {
+            inputs: inputs.clone(),
+            output: self.return_type.clone(),
+            body: Box::new(InvokeRustFunction {
+                function: format!("WASM_TABLE.data[{invoke}]"),
+                parameters: inputs,
+                output_type: self.return_type.clone(),
+            }),
+        };
+        block.render(ctx)?;
+
+        text!(ctx, "return ");
+        list!(ctx, ctx.pop_many(block.get_outputs()));
+        line!(ctx);
+        pull!(ctx, "end");
+
+        ctx.vars.unscope();
+
+        line!(ctx, "WASM_CLOSURES[{data}] = {closure}");
+        pull!(ctx, "end");
+
+        ctx.push(closure);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        2
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::{self, Write};
+
+use roblox_rs_shared_context::shared_context::SharedEnumKind;
+
+use crate::codegen::{
+    macros::{line, pull, push},
+    traits::{Instruction, InstructionContext},
+};
+
+/// Creates the lookup tables for a Rust enum, and exposes its variants as a frozen table.
+pub struct WasmCreateEnum {
+    pub rust_name: String,
+    pub luau_name: String,
+    pub kind: SharedEnumKind,
+    /// The Luau name and discriminant of each variant, in declaration order.
+    pub variants: Vec<(String, i64)>,
+}
+
+impl Instruction for WasmCreateEnum {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        ctx.vars.scope();
+
+        let rust_name = &self.rust_name;
+        let luau_name = &self.luau_name;
+        let values = ctx.vars.next("values");
+        let indices = ctx.vars.next("indices");
+        let variants = ctx.vars.next("variants");
+        let index = ctx.vars.next("index");
+        let value = ctx.vars.next("value");
+
+        push!(ctx, "do");
+        push!(ctx, "local {values} = {{");
+        for (name, discriminant) in &self.variants {
+            match self.kind {
+                SharedEnumKind::String => line!(ctx, "\"{name}\","),
+                SharedEnumKind::Number => line!(ctx, "{discriminant},"),
+            }
+        }
+        pull!(ctx, "}}");
+        line!(ctx);
+        line!(ctx, "local {indices} = {{}}");
+        line!(ctx, "local {variants} = {{}}");
+        push!(ctx, "for {index}, {value} in {values} do");
+        line!(ctx, "{indices}[{value}] = {index} - 1");
+        pull!(ctx, "end");
+        for (i, (name, _)) in self.variants.iter().enumerate() {
+            line!(ctx, "{variants}[\"{name}\"] = {values}[{}]", i + 1);
+        }
+        line!(ctx);
+        line!(
+            ctx,
+            "WASM_ENUMS[\"{rust_name}\"] = {{ values = {values}, indices = {indices} }}"
+        );
+        line!(
+            ctx,
+            "WASM_EXPORTS[\"{luau_name}\"] = table.freeze({variants})"
+        );
+        pull!(ctx, "end");
+
+        ctx.vars.unscope();
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        0
+    }
+
+    fn get_outputs(&self) -> usize {
+        0
+    }
+}
@@ -2,7 +2,9 @@ use std::io::{self, Write};
 
 use crate::{
     codegen::{
-        instructions::conversion::{LuauToRust, RustToLuau},
+        instructions::conversion::{
+            LuauReleaseExternRef, LuauToRust, RustToLuau, RustWriteBackToLuau,
+        },
         macros::{line, list, pull, push, text},
         traits::{Instruction, InstructionContext},
     },
@@ -10,6 +12,8 @@ use crate::{
 };
 
 pub struct WasmCreateExport {
+    /// The table expression that the function will be assigned to, e.g `WASM_EXPORTS`.
+    pub target: String,
     pub luau_name: String,
     pub parameters: Vec<Describe>,
     pub body: Box<dyn Instruction>,
@@ -19,10 +23,11 @@ impl Instruction for WasmCreateExport {
     fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
         ctx.vars.scope();
 
+        let target = &self.target;
         let luau_name = &self.luau_name;
         let parameters = ctx.vars.many(self.parameters.len(), "param");
 
-        text!(ctx, "WASM_EXPORTS[\"{luau_name}\"] = function(");
+        text!(ctx, "{target}[\"{luau_name}\"] = function(");
         list!(ctx, parameters);
         push!(ctx, ")");
 
@@ -30,8 +35,9 @@ impl Instruction for WasmCreateExport {
 
         self.body.render(ctx)?;
 
-        let value = ctx.pop();
-        line!(ctx, "return {value}");
+        text!(ctx, "return ");
+        list!(ctx, ctx.pop_many(self.body.get_outputs()));
+        line!(ctx);
         pull!(ctx, "end");
 
         ctx.vars.unscope();
@@ -58,19 +64,41 @@ pub struct ExportBlock {
 impl Instruction for ExportBlock {
     fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
         let inputs = ctx.pop_many(self.inputs.len());
+        let mut write_backs = Vec::new();
+        let mut temporaries = Vec::new();
 
         for (param, ty) in inputs.iter().zip(self.inputs.iter()) {
             ctx.push(param);
 
             LuauToRust { ty }.render(ctx)?;
+
+            if ty.writes_back() {
+                let values = ctx.inputs[ctx.inputs.len() - ty.value_count()..].to_vec();
+                write_backs.push((ty, param, values));
+            } else if ty.is_temporary() {
+                temporaries.push(ctx.inputs.last().unwrap().clone());
+            }
         }
 
         self.body.render(ctx)?;
 
-        if self.body.get_outputs() != 0 {
-            RustToLuau { ty: &self.output }.render(ctx)?;
+        // Mutably borrowed lists are copied back into the table they came from.
+        for (ty, param, values) in write_backs {
+            ctx.push(param);
+            ctx.inputs.extend(values);
+
+            RustWriteBackToLuau { ty }.render(ctx)?;
+        }
+
+        for heap in temporaries {
+            ctx.push(heap);
+
+            LuauReleaseExternRef.render(ctx)?;
         }
 
+        // Void outputs are converted too, so there is always a value to return.
+        RustToLuau { ty: &self.output }.render(ctx)?;
+
         Ok(())
     }
 
@@ -79,19 +107,20 @@ impl Instruction for ExportBlock {
     }
 
     fn get_outputs(&self) -> usize {
-        1
+        RustToLuau { ty: &self.output }.get_outputs()
     }
 }
 
 pub struct InvokeRustFunction {
-    pub function_name: String,
+    /// The expression for the Rust function, e.g `WASM.func_list.name`.
+    pub function: String,
     pub parameters: Vec<Describe>,
     pub output_type: Describe,
 }
 
 impl Instruction for InvokeRustFunction {
     fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
-        let function_name = &self.function_name;
+        let function = &self.function;
         let output_size = self.output_type.memory_size();
         let output_count = self.output_type.value_count();
         let mut inputs = Vec::new();
@@ -126,13 +155,30 @@ impl Instruction for InvokeRustFunction {
             }
         }
 
-        line!(ctx, "WASM.func_list.{function_name}({})", inputs.join(", "));
+        // Errors raised inside of Rust, such as panics, restore the stack pointer before propagating.
+        let stack = match &spill_ptr {
+            Some(spill_ptr) => format!("{spill_ptr} + {output_size}"),
+            None => "WASM_STACK.value".to_string(),
+        };
 
-        if let Some(spill_ptr) = &spill_ptr {
-            let primitives = &self.output_type.primitive_values();
-            ctx.push(spill_ptr);
+        text!(ctx, "WASM_UNWIND({stack}, pcall({function}");
+        for input in &inputs {
+            text!(ctx, ", {input}");
+        }
+        line!(ctx, "))");
 
-            PullMemory { primitives }.render(ctx)?;
+        if let Some(spill_ptr) = &spill_ptr {
+            for (offset, primitives) in self.output_type.memory_parts() {
+                match offset {
+                    0 => ctx.push(spill_ptr),
+                    offset => ctx.push(format!("{spill_ptr} + {offset}")),
+                }
+
+                PullMemory {
+                    primitives: &primitives,
+                }
+                .render(ctx)?;
+            }
 
             for (name, expr) in output_names.iter().zip(ctx.pop_many(output_count)) {
                 line!(ctx, "local {name} = {expr}");
@@ -11,19 +11,262 @@ const RUNTIME_HEAD: &str = "\
 local WASM_CTOR = require(script.Parent.wasm)
 local WASM_FUNCS = {}
 local WASM_EXPORTS = {}
-local HEAP, HEAP_ID = {}, 0
-local WASM, MEMORY, WASM_STACK";
+local WASM_CLASSES = {}
+local WASM_ENUMS = {}
+local WASM_CLOSURES = {}
+local WASM, MEMORY, WASM_STACK, WASM_TABLE
+
+local function WASM_CLOSURE_DROP(data)
+	WASM_CLOSURES[data] = nil
+end
+
+local function WASM_PANIC(message)
+	error(message, 0)
+end
+
+-- Rust frames aren't unwound by an error, so the shadow stack is reset to where it was before calling into Rust.
+local function WASM_UNWIND(stack, success, ...)
+	if not success then
+		WASM_STACK.value = stack
+		error((...), 0)
+	end
+
+	return ...
+end
+
+local function WASM_CALL(func, ...)
+	return (func(...))
+end
+
+local function WASM_CALL_METHOD(object, name, ...)
+	return (object[name](object, ...))
+end
+
+local function WASM_NAMESPACE_CALL(object, name, ...)
+	if typeof(object) == \"Instance\" then
+		return object[name](object, ...)
+	end
+
+	return object[name](...)
+end
+
+local function WASM_INDEX(object, key)
+	return object[key]
+end
+
+local function WASM_NEW_INDEX(object, key, value)
+	object[key] = value
+end
+
+local function WASM_IS_NIL(value)
+	return value == nil
+end
+
+local function WASM_TYPEOF(value)
+	return typeof(value)
+end
+
+local function WASM_IDENTITY(value)
+	return value
+end
+
+local function WASM_EXPECT(value, expected)
+	if type(value) ~= expected then
+		error(`expected {expected}, got {typeof(value)}`, 3)
+	end
+
+	return value
+end
+
+-- 64-bit integers are split into two 32-bit halves, and must fit within a number's 53 bits of precision.
+local function WASM_JOIN_WIDE(low, high)
+	if high >= 0x200000 or high < -0x200000 then
+		error(\"64-bit integer is too large to be represented as a number\", 3)
+	end
+
+	return low + high * 0x100000000
+end
+
+local function WASM_SPLIT_WIDE(value, signed)
+	if type(value) ~= \"number\" or value % 1 ~= 0 or math.abs(value) > 2 ^ 53 or (not signed and value < 0) then
+		error(`expected an integer which fits in {if signed then \"an i64\" else \"a u64\"}, got {value}`, 3)
+	end
+
+	return value % 0x100000000, value // 0x100000000
+end
+
+local function WASM_TO_CHAR(value)
+	if type(value) ~= \"string\" or utf8.len(value) ~= 1 then
+		error(`expected a single character string, got {value}`, 3)
+	end
+
+	local codepoint = utf8.codepoint(value)
+	if codepoint >= 0xD800 and codepoint <= 0xDFFF then
+		error(`expected a unicode scalar value, got surrogate {string.format(\"U+%04X\", codepoint)}`, 3)
+	end
+
+	return codepoint
+end
+
+local function WASM_EXPECT_TYPEOF(value, expected)
+	if typeof(value) ~= expected then
+		error(`expected {expected}, got {typeof(value)}`, 3)
+	end
+
+	return value
+end
+
+local function WASM_FROM_ENUM_ITEM(value)
+	return WASM_EXPECT_TYPEOF(value, \"EnumItem\").Value
+end
+
+-- `utf8.len` accepts encoded UTF-16 surrogates, which aren't valid UTF-8 in Rust.
+local function WASM_TO_UTF8(value)
+	if not utf8.len(WASM_EXPECT(value, \"string\")) or string.find(value, \"\\237[\\160-\\191]\") then
+		error(\"expected a valid UTF-8 string\", 3)
+	end
+
+	return value
+end
+
+local function WASM_TO_NUMBER(value)
+	return WASM_EXPECT(value, \"number\")
+end
+
+local function WASM_TO_INTEGER(value, min, max)
+	if type(value) ~= \"number\" or value % 1 ~= 0 or value < min or value > max then
+		error(`expected an integer between {min} and {max}, got {value}`, 3)
+	end
+
+	return value
+end
+
+local function WASM_TO_BOOLEAN(value)
+	return WASM_EXPECT(value, \"boolean\")
+end
+
+local function WASM_TRY_NUMBER(value)
+	return if type(value) == \"number\" then value else nil
+end
+
+local function WASM_TRY_BOOLEAN(value)
+	return if type(value) == \"boolean\" then value else nil
+end
+
+local function WASM_TRY_STRING(value)
+	return if type(value) == \"string\" then value else nil
+end
+
+local function WASM_TO_STRING(value)
+	return WASM_EXPECT(value, \"string\")
+end";
+
+/// Values owned by Rust are stored in `HEAP`, and released slots are reused through a free list to keep it dense.
+///
+/// Slots holding `nil` store `HEAP_NIL` instead, so an empty slot always means it has been released.
+///
+/// When `WASM_HEAP_DEBUG` is enabled, ids also hold the generation of their slot, so using a released value raises an error.
+const RUNTIME_HEAP: &str = "\
+local HEAP, HEAP_SIZE = {}, 0
+local HEAP_FREE, HEAP_FREE_COUNT = {}, 0
+local HEAP_GENERATIONS = {}
+local HEAP_NIL = newproxy()
+
+local function WASM_HEAP_SLOT(id)
+	if not WASM_HEAP_DEBUG then
+		return id
+	end
+
+	local slot = id % 0x100000
+	if HEAP_GENERATIONS[slot] ~= id // 0x100000 then
+		error(\"attempt to use a value which has already been released\", 3)
+	end
+
+	return slot
+end
+
+local function WASM_HEAP_ALLOC(value)
+	local slot
+	if HEAP_FREE_COUNT > 0 then
+		slot = HEAP_FREE[HEAP_FREE_COUNT]
+		HEAP_FREE[HEAP_FREE_COUNT] = nil
+		HEAP_FREE_COUNT -= 1
+	else
+		-- Debug ids hold the slot in their low 20 bits, so any more slots would share ids.
+		if WASM_HEAP_DEBUG and HEAP_SIZE >= 0xFFFFF then
+			error(\"too many Luau values are held by Rust to track their generations\", 2)
+		end
+
+		HEAP_SIZE += 1
+		slot = HEAP_SIZE
+	end
+
+	if value == nil then
+		HEAP[slot] = HEAP_NIL
+	else
+		HEAP[slot] = value
+	end
+
+	if not WASM_HEAP_DEBUG then
+		return slot
+	end
+
+	local generation = HEAP_GENERATIONS[slot] or 0
+	HEAP_GENERATIONS[slot] = generation
+
+	return slot + generation * 0x100000
+end
+
+local function WASM_HEAP_GET(id)
+	local value = HEAP[WASM_HEAP_SLOT(id)]
+	if value == HEAP_NIL then
+		return nil
+	end
+
+	return value
+end
+
+local function WASM_HEAP_DROP(id)
+	local slot = WASM_HEAP_SLOT(id)
+
+	-- Releasing an empty slot twice would put it in the free list twice, and two values would share it.
+	if HEAP[slot] == nil then
+		return
+	end
+
+	HEAP[slot] = nil
+	HEAP_FREE_COUNT += 1
+	HEAP_FREE[HEAP_FREE_COUNT] = slot
+
+	if WASM_HEAP_DEBUG then
+		HEAP_GENERATIONS[slot] = (HEAP_GENERATIONS[slot] + 1) % 0x1000
+	end
+end
+
+local function WASM_HEAP_TAKE(id)
+	local value = WASM_HEAP_GET(id)
+	WASM_HEAP_DROP(id)
+
+	return value
+end";
 
 const RUNTIME_TAIL: &str = "\
 WASM = WASM_CTOR({ luau = { func_list = WASM_FUNCS } })
 MEMORY = WASM.memory_list.memory
-WASM_STACK = WASM.global_list.__stack_pointer";
+WASM_STACK = WASM.global_list.__stack_pointer
+WASM_TABLE = WASM.table_list.__func_table";
 
-pub struct CreateRuntimeHeader;
+pub struct CreateRuntimeHeader {
+    /// Whether the generations of `HEAP` slots are tracked, to detect values which are used after being released.
+    pub debug_heap: bool,
+}
 
 impl Instruction for CreateRuntimeHeader {
     fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
         line!(ctx, "{RUNTIME_HEAD}");
+        line!(ctx);
+        line!(ctx, "local WASM_HEAP_DEBUG = {}", self.debug_heap);
+        line!(ctx, "{RUNTIME_HEAP}");
 
         Ok(())
     }
@@ -45,6 +288,10 @@ impl Instruction for CreateRuntimeTail {
     fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
         line!(ctx, "{RUNTIME_TAIL}");
 
+        // The panic hook is installed first, so panics from any Rust code are raised as Luau errors.
+        let set_panic_hook = ctx.intrinsics.get("set_panic_hook");
+        line!(ctx, "{set_panic_hook}()");
+
         for name in &self.main_names {
             line!(ctx, "WASM.func_list.{name}()");
         }
@@ -8,7 +8,7 @@ use crate::{
     describe::{Describe, Primitive},
 };
 
-use super::conversion::{LuauToRust, RustToLuau};
+use super::conversion::{LuauToRust, LuauWriteBackToRust, RustToLuau};
 
 pub struct WasmCreateImport {
     pub export_name: String,
@@ -42,10 +42,20 @@ impl Instruction for WasmCreateImport {
         self.body.render(ctx)?;
 
         if let Some(ptr) = out_param {
-            let primitives = &self.output.primitive_values();
-            ctx.push(ptr);
-
-            WriteMemory { primitives }.render(ctx)?;
+            let mut values = ctx.pop_many(self.body.get_outputs()).into_iter();
+
+            for (offset, primitives) in self.output.memory_parts() {
+                ctx.inputs.extend(values.by_ref().take(primitives.len()));
+                match offset {
+                    0 => ctx.push(&ptr),
+                    offset => ctx.push(format!("{ptr} + {offset}")),
+                }
+
+                WriteMemory {
+                    primitives: &primitives,
+                }
+                .render(ctx)?;
+            }
         } else {
             text!(ctx, "return ");
             list!(ctx, ctx.pop_many(self.body.get_outputs()));
@@ -69,6 +79,8 @@ impl Instruction for WasmCreateImport {
 }
 
 pub struct InvokeLuauFunction {
+    /// The path the function is resolved against, otherwise the function is a global.
+    pub namespace: Option<String>,
     pub function_name: String,
     pub parameter_count: usize,
     pub result_count: usize,
@@ -88,8 +100,24 @@ impl Instruction for InvokeLuauFunction {
             outputs.extend(var_names);
         }
 
-        text!(ctx, "{}(", self.function_name);
-        list!(ctx, ctx.pop_many(self.parameter_count));
+        let arguments = ctx.pop_many(self.parameter_count);
+        match &self.namespace {
+            // Functions of instances are methods, so the runtime decides whether to call it using `:` or `.`.
+            Some(namespace) => {
+                text!(
+                    ctx,
+                    "WASM_NAMESPACE_CALL({namespace}, \"{}\"",
+                    self.function_name
+                );
+                for argument in arguments {
+                    text!(ctx, ", {argument}");
+                }
+            }
+            None => {
+                text!(ctx, "{}(", self.function_name);
+                list!(ctx, arguments);
+            }
+        }
         line!(ctx, ")");
 
         ctx.inputs.extend(outputs);
@@ -106,6 +134,152 @@ impl Instruction for InvokeLuauFunction {
     }
 }
 
+/// Calls a method using Luau's colon syntax, e.g `object:Method(...)`.
+pub struct InvokeLuauMethod {
+    /// The object to call the method on, otherwise the first parameter is used.
+    pub object: Option<String>,
+    pub method_name: String,
+    pub parameter_count: usize,
+    pub result_count: usize,
+}
+
+impl Instruction for InvokeLuauMethod {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let mut outputs = Vec::new();
+        let (arguments, object) = match &self.object {
+            Some(object) => (ctx.pop_many(self.parameter_count), object.clone()),
+            None => (ctx.pop_many(self.parameter_count - 1), ctx.pop_complex()?),
+        };
+
+        if self.result_count > 0 {
+            let var_names = ctx.vars.many(self.result_count, "result");
+
+            text!(ctx, "local ");
+            list!(ctx, var_names);
+            text!(ctx, " = ");
+
+            outputs.extend(var_names);
+        }
+
+        text!(ctx, "{object}:{}(", self.method_name);
+        list!(ctx, arguments);
+        line!(ctx, ")");
+
+        ctx.inputs.extend(outputs);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        self.parameter_count
+    }
+
+    fn get_outputs(&self) -> usize {
+        self.result_count
+    }
+}
+
+/// Reads a property from the object, e.g `object.Property`.
+pub struct LuauGetProperty {
+    /// The object to read the property from, otherwise the input is used.
+    pub object: Option<String>,
+    pub property_name: String,
+}
+
+impl Instruction for LuauGetProperty {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let object = match &self.object {
+            Some(object) => object.clone(),
+            None => ctx.pop_complex()?,
+        };
+        let result = ctx.vars.next("result");
+
+        line!(ctx, "local {result} = {object}.{}", self.property_name);
+
+        ctx.push(result);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        usize::from(self.object.is_none())
+    }
+
+    fn get_outputs(&self) -> usize {
+        1
+    }
+}
+
+/// Assigns the last input to a property of the object, e.g `object.Property = value`.
+pub struct LuauSetProperty {
+    /// The object to assign the property on, otherwise the first input is used.
+    pub object: Option<String>,
+    pub property_name: String,
+}
+
+impl Instruction for LuauSetProperty {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let value = ctx.pop();
+        let object = match &self.object {
+            Some(object) => object.clone(),
+            None => ctx.pop_complex()?,
+        };
+
+        line!(ctx, "{object}.{} = {value}", self.property_name);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        1 + usize::from(self.object.is_none())
+    }
+
+    fn get_outputs(&self) -> usize {
+        0
+    }
+}
+
+/// Runs the body inside of `pcall`, outputting whether it succeeded and either its results or the error.
+pub struct LuauProtectedCall {
+    pub body: Box<dyn Instruction>,
+}
+
+impl Instruction for LuauProtectedCall {
+    fn render(&self, ctx: &mut InstructionContext) -> io::Result<()> {
+        let success = ctx.vars.next("success");
+        let results = ctx.vars.many(self.get_outputs() - 1, "result");
+
+        text!(ctx, "local {success}, ");
+        list!(ctx, results);
+        push!(ctx, " = pcall(function()");
+
+        self.body.render(ctx)?;
+
+        let outputs = ctx.pop_many(self.body.get_outputs());
+        if !outputs.is_empty() {
+            text!(ctx, "return ");
+            list!(ctx, outputs);
+            line!(ctx);
+        }
+
+        pull!(ctx, "end)");
+
+        ctx.push(success);
+        ctx.inputs.extend(results);
+
+        Ok(())
+    }
+
+    fn get_inputs(&self) -> usize {
+        self.body.get_inputs()
+    }
+
+    fn get_outputs(&self) -> usize {
+        // There is always a result, since it holds the error when the call fails.
+        1 + self.body.get_outputs().max(1)
+    }
+}
+
 /// This is a block that automatically converts the inputs from Rust to Luau, and outputs from Luau to Rust
 pub struct ImportBlock {
     pub inputs: Vec<Describe>,
@@ -122,14 +296,28 @@ impl Instruction for ImportBlock {
             inputs.push((param, names));
         }
 
+        let mut write_backs = Vec::new();
+
         for (ty, names) in inputs.into_iter().rev() {
-            ctx.inputs.extend(names);
+            ctx.inputs.extend(names.clone());
 
             RustToLuau { ty }.render(ctx)?;
+
+            if ty.writes_back() {
+                write_backs.push((ty, ctx.inputs.last().unwrap().clone(), names));
+            }
         }
 
         self.body.render(ctx)?;
 
+        // Changes the Luau function made to mutably borrowed slices are copied back into memory.
+        for (ty, table, names) in write_backs {
+            ctx.push(table);
+            ctx.inputs.extend(names);
+
+            LuauWriteBackToRust { ty }.render(ctx)?;
+        }
+
         if self.body.get_outputs() != 0 {
             LuauToRust { ty: &self.output }.render(ctx)?;
         }
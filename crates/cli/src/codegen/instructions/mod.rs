@@ -1,8 +1,12 @@
+pub mod classes;
 pub mod conversion;
+pub mod enums;
 pub mod exports;
 pub mod headers;
 pub mod imports;
 
+pub use classes::*;
+pub use enums::*;
 pub use exports::*;
 pub use imports::*;
 
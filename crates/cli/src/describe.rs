@@ -16,6 +16,25 @@ const EXTERNREF: u32 = 14;
 const STRING: u32 = 15;
 const SLICE: u32 = 16;
 const VECTOR: u32 = 17;
+const CLASS: u32 = 18;
+const RESULT: u32 = 19;
+const TUPLE: u32 = 20;
+const VARIADIC: u32 = 21;
+const ENUM: u32 = 22;
+const TABLE: u32 = 23;
+const MAP: u32 = 24;
+const CHAR: u32 = 25;
+const U64: u32 = 26;
+const I64: u32 = 27;
+const BUFFER: u32 = 28;
+const BYTES: u32 = 29;
+const ENUM_ITEM: u32 = 30;
+const VECTOR2: u32 = 31;
+const VECTOR3: u32 = 32;
+const CFRAME: u32 = 33;
+const COLOR3: u32 = 34;
+const UDIM2: u32 = 35;
+const RECT: u32 = 36;
 
 #[derive(Debug, Clone)]
 pub enum Describe {
@@ -25,12 +44,17 @@ pub enum Describe {
     I8,
     I16,
     I32,
+    U64,
+    I64,
+    Char,
     Boolean,
     Void,
     F32,
     F64,
     ExternRef,
     String,
+    Bytes,
+    Buffer,
     Vector {
         ty: Box<Describe>,
     },
@@ -50,6 +74,33 @@ pub enum Describe {
     Option {
         ty: Box<Describe>,
     },
+    Class {
+        name: String,
+    },
+    Result {
+        ty: Box<Describe>,
+    },
+    Tuple {
+        items: Vec<Describe>,
+    },
+    Variadic {
+        ty: Box<Describe>,
+    },
+    Enum {
+        name: String,
+    },
+    EnumItem,
+    DataType {
+        ty: DataType,
+    },
+    Table {
+        name: String,
+        fields: Vec<(String, Describe)>,
+    },
+    Map {
+        key: Box<Describe>,
+        value: Box<Describe>,
+    },
 }
 
 impl Describe {
@@ -64,37 +115,129 @@ impl Describe {
             | Describe::I32
             | Describe::F32
             | Describe::F64
+            | Describe::Char
             | Describe::Boolean
-            | Describe::ExternRef => 1,
-            Describe::Function { .. } => unimplemented!(),
+            | Describe::ExternRef
+            | Describe::Class { .. }
+            | Describe::Enum { .. }
+            | Describe::EnumItem
+            | Describe::Table { .. } => 1,
+            Describe::DataType { ty } if ty.is_boxed() => 1,
+            Describe::DataType { ty } => ty.components().len(),
+            Describe::Function { .. } => 2,
+            Describe::U64 | Describe::I64 => 2,
             Describe::Option { ty } => 1 + ty.value_count(),
+            Describe::Result { ty } => 1 + ty.value_count(),
+            Describe::Tuple { items } => items.iter().map(|v| v.value_count()).sum(),
             Describe::Ref { ty } => ty.value_count(),
+            // Mutably borrowed vectors are passed as a pointer to their header, since they can be resized.
+            Describe::RefMut { ty } if matches!(**ty, Describe::Vector { .. }) => 1,
             Describe::RefMut { ty } => ty.value_count(),
             Describe::Slice { .. } => 2,
             Describe::Vector { .. } => 2,
-            Describe::String => 2,
+            Describe::Variadic { .. } => 2,
+            Describe::Map { .. } => 2,
+            Describe::String | Describe::Bytes => 2,
+            Describe::Buffer => 2,
+        }
+    }
+
+    /// Whether the value is a single primitive, whose memory layout is the same in Rust and Luau.
+    pub fn is_primitive(&self) -> bool {
+        matches!(
+            self,
+            Describe::U8
+                | Describe::U16
+                | Describe::U32
+                | Describe::I8
+                | Describe::I16
+                | Describe::I32
+                | Describe::F32
+                | Describe::F64
+        )
+    }
+
+    /// Whether a slice of the value is lent by Rust as it is, rather than copied into a temporary array.
+    pub fn is_lent(&self) -> bool {
+        match self {
+            Describe::DataType { ty } => !ty.is_boxed(),
+            ty => ty.is_primitive(),
         }
     }
 
+    /// Whether the value is or contains a tuple, which is only supported as the top-level return type.
+    pub fn contains_tuple(&self) -> bool {
+        match self {
+            Describe::Tuple { .. } => true,
+            Describe::Vector { ty }
+            | Describe::Slice { ty }
+            | Describe::Ref { ty }
+            | Describe::RefMut { ty }
+            | Describe::Option { ty }
+            | Describe::Result { ty }
+            | Describe::Variadic { ty } => ty.contains_tuple(),
+            Describe::Function { args, return_type } => {
+                args.iter().any(|v| v.contains_tuple()) || return_type.contains_tuple()
+            }
+            Describe::Map { key, value } => key.contains_tuple() || value.contains_tuple(),
+            Describe::Table { fields, .. } => fields.iter().any(|(_, ty)| ty.contains_tuple()),
+            _ => false,
+        }
+    }
+
+    /// Whether the value is a mutable borrow of a list, whose changes are written back once the call has finished.
+    pub fn writes_back(&self) -> bool {
+        match self {
+            Describe::RefMut { ty } => {
+                matches!(**ty, Describe::Slice { .. } | Describe::Vector { .. })
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the value is borrowed from a temporary `HEAP` entry, which is removed once the call has finished.
+    pub fn is_temporary(&self) -> bool {
+        matches!(self, Describe::Ref { ty } if matches!(**ty, Describe::ExternRef))
+    }
+
     pub fn max_align(&self) -> usize {
-        self.primitive_values()
-            .iter()
-            .map(|v| v.byte_size())
-            .max()
-            .unwrap()
+        Primitive::max_align(&self.primitive_values())
     }
 
     pub fn memory_size(&self) -> usize {
-        let mut size = 0;
-        let mut max_align = 0;
+        match self.memory_parts().as_slice() {
+            [(_, primitives)] => Primitive::memory_size(primitives),
+            parts => {
+                let (offset, primitives) = parts.last().unwrap();
+                let align = self.max_align() - 1;
 
-        for prim in self.primitive_values() {
-            let byte_size = prim.byte_size();
-            size = prim.next_align(size) + byte_size;
-            max_align = max_align.max(byte_size - 1);
+                (offset + Primitive::memory_size(primitives) + align) & !align
+            }
         }
+    }
 
-        (size + max_align) & !max_align
+    /// The primitive values split into the parts which are laid out in memory separately, with the offset of each part.
+    ///
+    /// Tuples are a struct of their own in Rust, so a tuple inside of a result is aligned to its largest primitive.
+    pub fn memory_parts(&self) -> Vec<(usize, Vec<Primitive>)> {
+        match self {
+            Describe::Result { ty } if matches!(**ty, Describe::Tuple { .. }) => {
+                let align = ty.max_align() - 1;
+                let offset = (Primitive::U32.byte_size() + align) & !align;
+
+                vec![(0, vec![Primitive::U32]), (offset, ty.primitive_values())]
+            }
+            ty => vec![(0, ty.primitive_values())],
+        }
+    }
+
+    /// The primitive values of the fields of a table, which are stored in memory behind the table's pointer.
+    pub fn field_primitives(fields: &[(String, Describe)]) -> Vec<Primitive> {
+        let mut vec = Vec::new();
+        for (_, ty) in fields {
+            ty._primitive_values(&mut vec);
+        }
+        vec
     }
 
     pub fn primitive_values(&self) -> Vec<Primitive> {
@@ -112,19 +255,43 @@ impl Describe {
             Describe::I16 => out.push(Primitive::I16),
             Describe::I32 => out.push(Primitive::I32),
             Describe::Boolean => out.push(Primitive::U32),
+            Describe::Char => out.push(Primitive::U32),
+            Describe::U64 => out.extend([Primitive::U32, Primitive::U32]),
+            Describe::I64 => out.extend([Primitive::U32, Primitive::I32]),
             Describe::ExternRef => out.push(Primitive::U32),
+            Describe::Class { .. } => out.push(Primitive::U32),
+            Describe::Enum { .. } => out.push(Primitive::U32),
+            Describe::EnumItem => out.push(Primitive::U32),
+            Describe::DataType { ty } if ty.is_boxed() => out.push(Primitive::U32),
+            Describe::DataType { ty } => out.extend(ty.components()),
+            Describe::Table { .. } => out.push(Primitive::U32),
             Describe::Void => {}
             Describe::F32 => out.push(Primitive::F32),
             Describe::F64 => out.push(Primitive::F64),
-            Describe::String => out.extend([Primitive::U32, Primitive::U32]),
+            Describe::String | Describe::Bytes => out.extend([Primitive::U32, Primitive::U32]),
+            Describe::Buffer => out.extend([Primitive::U32, Primitive::U32]),
             Describe::Vector { .. } => out.extend([Primitive::U32, Primitive::U32]),
+            Describe::Variadic { .. } => out.extend([Primitive::U32, Primitive::U32]),
+            Describe::Map { .. } => out.extend([Primitive::U32, Primitive::U32]),
             Describe::Slice { .. } => out.extend([Primitive::U32, Primitive::U32]),
-            Describe::Function { .. } => unimplemented!(),
+            Describe::Function { .. } => out.extend([Primitive::U32, Primitive::U32]),
+            Describe::RefMut { ty } if matches!(**ty, Describe::Vector { .. }) => {
+                out.push(Primitive::U32)
+            }
             Describe::Ref { ty } | Describe::RefMut { ty } => ty._primitive_values(out),
             Describe::Option { ty } => {
                 out.push(Primitive::U8);
                 ty._primitive_values(out);
             }
+            Describe::Result { ty } => {
+                out.push(Primitive::U32);
+                ty._primitive_values(out);
+            }
+            Describe::Tuple { items } => {
+                for item in items {
+                    item._primitive_values(out);
+                }
+            }
         }
     }
 
@@ -140,12 +307,34 @@ impl Describe {
             I8 => Describe::I8,
             I16 => Describe::I16,
             I32 => Describe::I32,
+            U64 => Describe::U64,
+            I64 => Describe::I64,
+            CHAR => Describe::Char,
             BOOLEAN => Describe::Boolean,
             VOID => Describe::Void,
             F32 => Describe::F32,
             F64 => Describe::F64,
             EXTERNREF => Describe::ExternRef,
             STRING => Describe::String,
+            BUFFER => Describe::Buffer,
+            BYTES => Describe::Bytes,
+            ENUM_ITEM => Describe::EnumItem,
+            VECTOR2 => Describe::DataType {
+                ty: DataType::Vector2,
+            },
+            VECTOR3 => Describe::DataType {
+                ty: DataType::Vector3,
+            },
+            CFRAME => Describe::DataType {
+                ty: DataType::CFrame,
+            },
+            COLOR3 => Describe::DataType {
+                ty: DataType::Color3,
+            },
+            UDIM2 => Describe::DataType {
+                ty: DataType::UDim2,
+            },
+            RECT => Describe::DataType { ty: DataType::Rect },
             VECTOR => Describe::Vector {
                 ty: Box::new(Describe::_parse(value)),
             },
@@ -174,6 +363,37 @@ impl Describe {
             OPTION => Describe::Option {
                 ty: Box::new(Describe::_parse(value)),
             },
+            CLASS => Describe::Class {
+                name: Describe::take_string(value),
+            },
+            MAP => Describe::Map {
+                key: Box::new(Describe::_parse(value)),
+                value: Box::new(Describe::_parse(value)),
+            },
+            TABLE => {
+                let name = Describe::take_string(value);
+                let field_count = Describe::take(value);
+                let fields = (0..field_count)
+                    .map(|_| (Describe::take_string(value), Describe::_parse(value)))
+                    .collect();
+
+                Describe::Table { name, fields }
+            }
+            ENUM => Describe::Enum {
+                name: Describe::take_string(value),
+            },
+            RESULT => Describe::Result {
+                ty: Box::new(Describe::_parse(value)),
+            },
+            VARIADIC => Describe::Variadic {
+                ty: Box::new(Describe::_parse(value)),
+            },
+            TUPLE => {
+                let item_count = Describe::take(value);
+                let items = (0..item_count).map(|_| Describe::_parse(value)).collect();
+
+                Describe::Tuple { items }
+            }
             _ => panic!("something is wrong"),
         }
     }
@@ -183,6 +403,71 @@ impl Describe {
         *value = &value[1..];
         first
     }
+
+    fn take_string(value: &mut &[u32]) -> String {
+        let len = Describe::take(value);
+        let bytes = (0..len).map(|_| Describe::take(value) as u8).collect();
+
+        String::from_utf8(bytes).expect("describe string is not utf-8")
+    }
+}
+
+/// A Roblox data type, which is passed as its components rather than being kept in the `HEAP`.
+#[derive(Debug, Clone, Copy)]
+pub enum DataType {
+    Vector2,
+    Vector3,
+    CFrame,
+    Color3,
+    UDim2,
+    Rect,
+}
+
+impl DataType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DataType::Vector2 => "Vector2",
+            DataType::Vector3 => "Vector3",
+            DataType::CFrame => "CFrame",
+            DataType::Color3 => "Color3",
+            DataType::UDim2 => "UDim2",
+            DataType::Rect => "Rect",
+        }
+    }
+
+    /// The primitives of the components, in the order they're passed to the type's `new` constructor.
+    pub fn components(&self) -> Vec<Primitive> {
+        match self {
+            DataType::Vector2 => vec![Primitive::F32; 2],
+            DataType::Vector3 | DataType::Color3 => vec![Primitive::F32; 3],
+            DataType::Rect => vec![Primitive::F32; 4],
+            DataType::UDim2 => vec![
+                Primitive::F32,
+                Primitive::I32,
+                Primitive::F32,
+                Primitive::I32,
+            ],
+            DataType::CFrame => vec![Primitive::F32; 12],
+        }
+    }
+
+    /// Luau expressions which read each component out of a value of the type.
+    pub fn component_fields(&self) -> &'static [&'static str] {
+        match self {
+            DataType::Vector2 => &["X", "Y"],
+            DataType::Vector3 => &["X", "Y", "Z"],
+            DataType::Color3 => &["R", "G", "B"],
+            DataType::UDim2 => &["X.Scale", "X.Offset", "Y.Scale", "Y.Offset"],
+            DataType::Rect => &["Min.X", "Min.Y", "Max.X", "Max.Y"],
+            // The components of a `CFrame` are read all at once with `GetComponents`.
+            DataType::CFrame => &[],
+        }
+    }
+
+    /// Whether the components don't fit in the ABI, so they're passed as a pointer to memory instead.
+    pub fn is_boxed(&self) -> bool {
+        matches!(self, DataType::CFrame)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -198,6 +483,23 @@ pub enum Primitive {
 }
 
 impl Primitive {
+    pub fn max_align(primitives: &[Primitive]) -> usize {
+        primitives.iter().map(|v| v.byte_size()).max().unwrap()
+    }
+
+    pub fn memory_size(primitives: &[Primitive]) -> usize {
+        let mut size = 0;
+        let mut max_align = 0;
+
+        for prim in primitives {
+            let byte_size = prim.byte_size();
+            size = prim.next_align(size) + byte_size;
+            max_align = max_align.max(byte_size - 1);
+        }
+
+        (size + max_align) & !max_align
+    }
+
     pub fn byte_size(&self) -> usize {
         match self {
             Primitive::U8 => 1,
@@ -1,3 +1,4 @@
+mod bindings;
 mod build;
 mod codegen;
 mod describe;
@@ -23,6 +24,22 @@ enum Command {
 
         #[arg(long, short)]
         out: PathBuf,
+
+        /// Detects Luau values which are used after being released, at a small cost to performance.
+        #[arg(long)]
+        debug_heap: bool,
+    },
+
+    /// Generates a Rust module of Roblox classes, members and enums from an `API-Dump.json` file.
+    Bindings {
+        api_dump: PathBuf,
+
+        #[arg(long, short)]
+        out: PathBuf,
+
+        /// Includes members which are only accessible to plugins.
+        #[arg(long)]
+        plugin: bool,
     },
 }
 
@@ -32,12 +49,23 @@ fn main() {
     debug!("{args:?}");
 
     match args.command {
-        Command::Build { wasm_path, out } => {
+        Command::Build {
+            wasm_path,
+            out,
+            debug_heap,
+        } => {
             let module = ModuleConfig::new()
                 .parse(&fs::read(wasm_path).unwrap())
                 .expect("idiot?");
 
-            build::build(module, env::current_dir().unwrap().join(out));
+            build::build(module, env::current_dir().unwrap().join(out), debug_heap);
+        }
+        Command::Bindings {
+            api_dump,
+            out,
+            plugin,
+        } => {
+            bindings::generate(api_dump, env::current_dir().unwrap().join(out), plugin);
         }
     }
 }
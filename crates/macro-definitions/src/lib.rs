@@ -5,6 +5,14 @@ pub fn luau(attr: TokenStream, input: TokenStream) -> TokenStream {
     roblox_rs_macro_expansion::attribute::expand_attribute(attr.into(), input.into()).into()
 }
 
+/// Converts a struct to and from a Luau table with the same field names, nested structs must also derive `LuauTable`.
+///
+/// Fields can be renamed in Luau using the `name` attribute, e.g `#[luau(name = "Position")]`.
+#[proc_macro_derive(LuauTable, attributes(luau))]
+pub fn luau_table(input: TokenStream) -> TokenStream {
+    roblox_rs_macro_expansion::derive::expand_luau_table(input.into()).into()
+}
+
 #[doc(hidden)]
 #[proc_macro_attribute]
 pub fn intrinsic(attr: TokenStream, input: TokenStream) -> TokenStream {
@@ -2,54 +2,84 @@ use std::fmt::Debug;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use roblox_rs_shared_context::shared_context::SharedExportFunction;
-
-use crate::attribute::type_from_arg;
+use roblox_rs_shared_context::shared_context::{
+    SharedClass, SharedEnum, SharedEnumKind, SharedEnumVariant, SharedExportFunction,
+};
+use syn::Type;
 
 use super::{
     description::FunctionDescription,
     emit::{emit_id, get_abi_args, with_assoc, with_item, with_trait, Emit},
 };
 
-#[derive(Debug)]
 pub enum ContextExport {
-    Function(ExportFunction),
+    Function(Box<syn::ItemFn>, ExportFunction),
+    Class(ExportClass),
+    Impl(syn::ItemImpl, Vec<ExportFunction>),
+    Enum(ExportEnum),
 }
 
 impl Emit for ContextExport {
     fn emit(&self, tokens: &mut TokenStream) {
         match self {
-            ContextExport::Function(f) => f.emit(tokens),
+            ContextExport::Function(item, f) => {
+                f.emit(tokens);
+                item.to_tokens(tokens);
+            }
+            ContextExport::Class(class) => class.emit(tokens),
+            ContextExport::Enum(item) => item.emit(tokens),
+            ContextExport::Impl(item, methods) => {
+                for method in methods {
+                    method.emit(tokens);
+                }
+
+                item.to_tokens(tokens);
+            }
         }
     }
 }
 
-impl From<ContextExport> for SharedExportFunction {
-    fn from(value: ContextExport) -> Self {
-        match value {
-            ContextExport::Function(f) => Self {
-                describe_name: f.describe_name,
-                export_name: f.export_name,
-                luau_name: f.luau_name,
-                rust_name: f.rust_name,
-            },
+impl Debug for ContextExport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContextExport::Function(_, function) => write!(f, "Function({function:?})"),
+            ContextExport::Class(class) => write!(f, "Class({class:?})"),
+            ContextExport::Impl(_, methods) => write!(f, "Impl({methods:?})"),
+            ContextExport::Enum(item) => write!(f, "Enum({item:?})"),
+        }
+    }
+}
+
+impl From<ExportFunction> for SharedExportFunction {
+    fn from(value: ExportFunction) -> Self {
+        Self {
+            describe_name: value.describe_name,
+            export_name: value.export_name,
+            luau_name: value.luau_name,
+            rust_name: value.rust_name,
+            class: value.class,
         }
     }
 }
 
 pub struct ExportFunction {
-    pub item: syn::ItemFn,
     pub rust_name: String,
     pub luau_name: String,
     pub export_name: String,
     pub describe_name: String,
+    /// The Rust name of the class this function is defined on, if any.
+    pub class: Option<String>,
+    /// The path used to call the Rust function, e.g `my_func` or `<MyStruct>::my_func`.
+    pub invoke: TokenStream,
     pub arguments: Vec<syn::Type>,
+    /// Whether each argument is a `#[luau(lossy)]` string, which is received as `LuauBytes`.
+    pub lossy: Vec<bool>,
     pub return_type: Option<syn::Type>,
 }
 
 impl Emit for ExportFunction {
     fn emit(&self, tokens: &mut TokenStream) {
-        let name = &self.item.sig.ident;
+        let invoke = &self.invoke;
         let export_name = emit_id(&self.export_name);
 
         FunctionDescription {
@@ -59,23 +89,57 @@ impl Emit for ExportFunction {
         }
         .emit(tokens);
 
-        let mut arg_names = Vec::new();
+        let mut arg_exprs = Vec::new();
         let mut abi_args = Vec::new();
         let mut abi_arg_conversions = Vec::new();
-        for (i, arg) in self.item.sig.inputs.iter().enumerate() {
-            let ty = type_from_arg(arg);
+        for (i, (ty, lossy)) in self.arguments.iter().zip(&self.lossy).enumerate() {
             let arg_name = emit_id(format!("arg{i}"));
-            let from_abi = with_trait(&ty, "WasmFromAbi");
+
+            // References are borrowed through an anchor which lives until the end of the call.
+            let (ty, trait_name, method, mutability, arg_expr) = match ty {
+                Type::Reference(ty) if ty.mutability.is_some() => (
+                    &*ty.elem,
+                    "WasmRefMutFromAbi",
+                    "ref_mut_from_abi",
+                    quote! { mut },
+                    quote! { &mut *#arg_name },
+                ),
+                Type::Reference(ty) => (
+                    &*ty.elem,
+                    "WasmRefFromAbi",
+                    "ref_from_abi",
+                    quote! {},
+                    quote! { &*#arg_name },
+                ),
+                // Lossy strings replace invalid UTF-8, instead of the runtime raising an error.
+                ty if *lossy => (
+                    ty,
+                    "WasmFromAbi",
+                    "from_abi",
+                    quote! {},
+                    quote! { #arg_name.into_string_lossy() },
+                ),
+                ty => (
+                    ty,
+                    "WasmFromAbi",
+                    "from_abi",
+                    quote! {},
+                    quote! { #arg_name },
+                ),
+            };
+
+            let method = emit_id(method);
+            let from_abi = with_trait(ty, trait_name);
             let wasm_abi = with_trait(with_assoc(&from_abi, "Abi"), "WasmAbi");
-            let (names, args) = get_abi_args(&arg_name, "WasmFromAbi", &ty);
+            let (names, args) = get_abi_args(&arg_name, trait_name, ty);
             abi_arg_conversions.push(quote! {
-                let #arg_name = unsafe {
-                    #from_abi::from_abi(#wasm_abi::join(#(#names),*))
+                let #mutability #arg_name = unsafe {
+                    #from_abi::#method(#wasm_abi::join(#(#names),*))
                 };
             });
 
             abi_args.extend(args);
-            arg_names.push(arg_name);
+            arg_exprs.push(arg_expr);
         }
 
         let result_id = emit_id("result");
@@ -93,12 +157,10 @@ impl Emit for ExportFunction {
             #[no_mangle]
             extern "C" fn #export_name(#(#abi_args),*) #abi_return {
                 #(#abi_arg_conversions)*
-                let #result_id = #name(#(#arg_names),*);
+                let #result_id = #invoke(#(#arg_exprs),*);
                 #abi_return_conversion
             }
         });
-
-        self.item.to_tokens(tokens);
     }
 }
 
@@ -107,3 +169,192 @@ impl Debug for ExportFunction {
         write!(f, "ExportFunction")
     }
 }
+
+impl From<ExportClass> for SharedClass {
+    fn from(value: ExportClass) -> Self {
+        Self {
+            rust_name: value.rust_name,
+            luau_name: value.luau_name,
+            drop_name: value.drop_name,
+        }
+    }
+}
+
+/// A Rust struct which is exposed to Luau as a class, the Luau side only holds a pointer to the boxed value.
+pub struct ExportClass {
+    pub item: syn::ItemStruct,
+    pub rust_name: String,
+    pub luau_name: String,
+    pub drop_name: String,
+}
+
+impl Emit for ExportClass {
+    fn emit(&self, tokens: &mut TokenStream) {
+        let item = &self.item;
+        let ident = &item.ident;
+        let drop_name = emit_id(&self.drop_name);
+        let name_len = self.rust_name.len() as u32;
+        let name_bytes = self.rust_name.bytes().map(u32::from);
+
+        tokens.extend(quote! {
+            #item
+
+            const _: () = {
+                use roblox_rs::internal::*;
+
+                impl WasmDescribe for #ident {
+                    #[inline(always)]
+                    fn describe() {
+                        describe(CLASS);
+                        describe(#name_len);
+                        #(describe(#name_bytes);)*
+                    }
+                }
+
+                impl WasmIntoAbi for #ident {
+                    type Abi = u32;
+
+                    fn into_abi(self) -> Self::Abi {
+                        Box::into_raw(Box::new(self)) as u32
+                    }
+                }
+
+                impl WasmFromAbi for #ident {
+                    type Abi = u32;
+
+                    unsafe fn from_abi(value: Self::Abi) -> Self {
+                        *Box::from_raw(value as *mut #ident)
+                    }
+                }
+
+                impl WasmRefFromAbi for #ident {
+                    type Abi = u32;
+                    type Anchor = &'static #ident;
+
+                    unsafe fn ref_from_abi(value: Self::Abi) -> Self::Anchor {
+                        &*(value as *const #ident)
+                    }
+                }
+
+                impl WasmRefMutFromAbi for #ident {
+                    type Abi = u32;
+                    type Anchor = &'static mut #ident;
+
+                    unsafe fn ref_mut_from_abi(value: Self::Abi) -> Self::Anchor {
+                        &mut *(value as *mut #ident)
+                    }
+                }
+
+                #[no_mangle]
+                unsafe extern "C" fn #drop_name(value: u32) {
+                    drop(Box::from_raw(value as *mut #ident));
+                }
+            };
+        });
+    }
+}
+
+impl Debug for ExportClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ExportClass")
+    }
+}
+
+impl From<ExportEnum> for SharedEnum {
+    fn from(value: ExportEnum) -> Self {
+        Self {
+            rust_name: value.rust_name,
+            luau_name: value.luau_name,
+            kind: value.kind,
+            variants: value
+                .variants
+                .into_iter()
+                .map(|variant| SharedEnumVariant {
+                    luau_name: variant.luau_name,
+                    discriminant: variant.discriminant,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A C-like Rust enum which is exposed to Luau as strings or numbers, the index of the variant is sent over the ABI.
+pub struct ExportEnum {
+    pub item: syn::ItemEnum,
+    pub rust_name: String,
+    pub luau_name: String,
+    pub kind: SharedEnumKind,
+    pub variants: Vec<ExportEnumVariant>,
+}
+
+pub struct ExportEnumVariant {
+    pub ident: syn::Ident,
+    pub luau_name: String,
+    pub discriminant: i64,
+}
+
+impl Emit for ExportEnum {
+    fn emit(&self, tokens: &mut TokenStream) {
+        let item = &self.item;
+        let ident = &item.ident;
+        let name_len = self.rust_name.len() as u32;
+        let name_bytes = self.rust_name.bytes().map(u32::from);
+        let variants: Vec<_> = self.variants.iter().map(|v| &v.ident).collect();
+        let indices: Vec<_> = (0..self.variants.len() as u32).collect();
+        let invalid_variant = format!("invalid {} variant", self.rust_name);
+
+        tokens.extend(quote! {
+            #item
+
+            const _: () = {
+                use roblox_rs::internal::*;
+
+                impl WasmDescribe for #ident {
+                    #[inline(always)]
+                    fn describe() {
+                        describe(ENUM);
+                        describe(#name_len);
+                        #(describe(#name_bytes);)*
+                    }
+                }
+
+                impl WasmIntoAbi for #ident {
+                    type Abi = u32;
+
+                    fn into_abi(self) -> Self::Abi {
+                        match self {
+                            #(#ident::#variants => #indices,)*
+                        }
+                    }
+                }
+
+                impl WasmRefIntoAbi for #ident {
+                    type Abi = u32;
+
+                    fn ref_into_abi(&self) -> Self::Abi {
+                        match self {
+                            #(#ident::#variants => #indices,)*
+                        }
+                    }
+                }
+
+                impl WasmFromAbi for #ident {
+                    type Abi = u32;
+
+                    unsafe fn from_abi(value: Self::Abi) -> Self {
+                        match value {
+                            #(#indices => #ident::#variants,)*
+                            _ => panic!(#invalid_variant),
+                        }
+                    }
+                }
+            };
+        });
+    }
+}
+
+impl Debug for ExportEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ExportEnum")
+    }
+}
@@ -2,7 +2,8 @@ use std::fmt::Debug;
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use roblox_rs_shared_context::shared_context::SharedImportFunction;
+use roblox_rs_shared_context::shared_context::{SharedImportFunction, SharedImportKind};
+use syn::{Attribute, Ident, Visibility};
 
 use super::{
     description::FunctionDescription,
@@ -29,6 +30,8 @@ impl From<ContextImport> for SharedImportFunction {
                 luau_name: f.luau_name,
                 rust_name: f.rust_name,
                 export_name: f.export_name,
+                kind: f.kind,
+                namespace: value.namespace,
             },
         }
     }
@@ -48,6 +51,8 @@ impl Emit for ImportKind {
 }
 
 pub struct ImportFunction {
+    pub vis: Visibility,
+    pub kind: SharedImportKind,
     pub luau_name: String,
     pub rust_name: String,
     pub export_name: String,
@@ -73,6 +78,7 @@ impl Emit for ImportFunction {
             None => quote! {},
         };
 
+        let vis = &self.vis;
         let rust_name = emit_id(&self.rust_name);
         let abi_name = emit_id(&self.export_name);
         let mut abi_args = Vec::new();
@@ -112,7 +118,7 @@ impl Emit for ImportFunction {
         };
 
         tokens.extend(quote! {
-            fn #rust_name(#(#def_args),*) #def_return {
+            #vis fn #rust_name(#(#def_args),*) #def_return {
                 #abi_fn
                 #(#def_arg_conversion)*
                 let #return_id = unsafe { #abi_name(#(#abi_arg_names),*) };
@@ -127,3 +133,101 @@ impl Debug for ImportFunction {
         f.write_str("ImportFunction")
     }
 }
+
+/// A Luau type, which is represented in Rust as a distinct newtype around a `LuauValue`.
+pub struct ImportType {
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub ident: Ident,
+}
+
+impl Emit for ImportType {
+    fn emit(&self, tokens: &mut TokenStream) {
+        let attrs = &self.attrs;
+        let vis = &self.vis;
+        let ident = &self.ident;
+
+        tokens.extend(quote! {
+            #(#attrs)*
+            #[repr(transparent)]
+            #vis struct #ident(roblox_rs::prelude::LuauValue);
+
+            const _: () = {
+                use roblox_rs::internal::*;
+                use roblox_rs::prelude::LuauValue;
+
+                impl WasmDescribe for #ident {
+                    #[inline(always)]
+                    fn describe() {
+                        <LuauValue as WasmDescribe>::describe();
+                    }
+                }
+
+                impl WasmIntoAbi for #ident {
+                    type Abi = u32;
+
+                    fn into_abi(self) -> Self::Abi {
+                        self.0.into_abi()
+                    }
+                }
+
+                impl WasmIntoAbi for &#ident {
+                    type Abi = u32;
+
+                    fn into_abi(self) -> Self::Abi {
+                        (&self.0).into_abi()
+                    }
+                }
+
+                impl WasmRefIntoAbi for #ident {
+                    type Abi = u32;
+
+                    fn ref_into_abi(&self) -> Self::Abi {
+                        self.0.ref_into_abi()
+                    }
+                }
+
+                impl WasmFromAbi for #ident {
+                    type Abi = u32;
+
+                    unsafe fn from_abi(value: Self::Abi) -> Self {
+                        #ident(LuauValue::from_abi(value))
+                    }
+                }
+
+                impl WasmRefFromAbi for #ident {
+                    type Abi = u32;
+                    type Anchor = std::mem::ManuallyDrop<#ident>;
+
+                    unsafe fn ref_from_abi(value: Self::Abi) -> Self::Anchor {
+                        std::mem::ManuallyDrop::new(#ident(LuauValue::from_abi(value)))
+                    }
+                }
+
+                impl Clone for #ident {
+                    fn clone(&self) -> Self {
+                        #ident(self.0.clone())
+                    }
+                }
+
+                impl From<#ident> for LuauValue {
+                    fn from(value: #ident) -> Self {
+                        value.0
+                    }
+                }
+
+                impl AsRef<LuauValue> for #ident {
+                    fn as_ref(&self) -> &LuauValue {
+                        &self.0
+                    }
+                }
+            };
+        });
+    }
+}
+
+impl Debug for ImportType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ImportType")
+    }
+}
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 
 use emit::Emit;
 use export::ContextExport;
-use import::ContextImport;
+use import::{ContextImport, ImportType};
 use mains::ContextMain;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -20,6 +20,7 @@ use super::parse::ParsedAttributes;
 pub struct Context {
     pub attributes: ParsedAttributes,
     pub imports: Vec<ContextImport>,
+    pub types: Vec<ImportType>,
     pub exports: Vec<ContextExport>,
     pub main_fns: Vec<ContextMain>,
 }
@@ -28,6 +29,10 @@ impl Context {
     pub fn emit(self) -> TokenStream {
         let mut tokens = TokenStream::new();
 
+        for ty in &self.types {
+            ty.emit(&mut tokens);
+        }
+
         for import in &self.imports {
             import.emit(&mut tokens);
         }
@@ -40,13 +45,23 @@ impl Context {
             main.emit(&mut tokens);
         }
 
-        let shared_context = SharedContext {
+        let mut shared_context = SharedContext {
             imports: self.imports.into_iter().map(Into::into).collect(),
-            exports: self.exports.into_iter().map(Into::into).collect(),
             main_fns: self.main_fns.into_iter().map(|v| v.export_name).collect(),
-            intrinsics: Vec::new(),
+            ..Default::default()
         };
 
+        for export in self.exports {
+            match export {
+                ContextExport::Function(_, f) => shared_context.exports.push(f.into()),
+                ContextExport::Class(class) => shared_context.classes.push(class.into()),
+                ContextExport::Enum(item) => shared_context.enums.push(item.into()),
+                ContextExport::Impl(_, methods) => shared_context
+                    .exports
+                    .extend(methods.into_iter().map(Into::into)),
+            }
+        }
+
         let encoded_data = roblox_rs_shared_context::encode(&shared_context);
         let encoded_len = encoded_data.len();
 
@@ -1,14 +1,21 @@
-use syn::{ForeignItemFn, Item, ItemFn, ItemForeignMod, ReturnType};
+use quote::quote;
+use syn::{
+    parse_quote, punctuated::Punctuated, token::Comma, Expr, ExprLit, ExprUnary, Fields, FnArg,
+    ForeignItemFn, ForeignItemType, ImplItem, Item, ItemEnum, ItemFn, ItemForeignMod, ItemImpl,
+    ItemStruct, Lit, ReturnType, Type, UnOp, Visibility,
+};
+
+use roblox_rs_shared_context::shared_context::{SharedEnumKind, SharedImportKind};
 
 use crate::attribute::{parse::ParsedAttributes, symbol::new_symbol_name};
 
 use super::{
     context::{
-        export::{ContextExport, ExportFunction},
-        import::{ContextImport, ImportFunction, ImportKind},
+        export::{ContextExport, ExportClass, ExportEnum, ExportEnumVariant, ExportFunction},
+        import::{ContextImport, ImportFunction, ImportKind, ImportType},
         mains::ContextMain,
     },
-    type_from_arg, Context,
+    replace_self, type_from_arg, Context,
 };
 
 pub trait Expand {
@@ -20,6 +27,9 @@ impl Expand for Item {
         match self {
             Item::ForeignMod(item) => item.expand(ctx),
             Item::Fn(item) => item.expand(ctx),
+            Item::Struct(item) => item.expand(ctx),
+            Item::Impl(item) => item.expand(ctx),
+            Item::Enum(item) => item.expand(ctx),
             _ => {}
         }
     }
@@ -30,7 +40,7 @@ impl Expand for ItemForeignMod {
         for item in &self.items {
             match item {
                 syn::ForeignItem::Fn(f) => f.expand(ctx),
-                syn::ForeignItem::Type(_) => (),
+                syn::ForeignItem::Type(ty) => ty.expand(ctx),
                 _ => (),
             }
         }
@@ -40,12 +50,42 @@ impl Expand for ItemForeignMod {
 impl Expand for ForeignItemFn {
     fn expand(&self, ctx: &mut Context) {
         let attributes = ParsedAttributes::fetch(&self.attrs);
-        let namespace = attributes.namespace;
+        let namespace = attributes
+            .namespace
+            .or_else(|| ctx.attributes.namespace.clone());
         let rust_name = self.sig.ident.to_string();
-        let luau_name = attributes.name.as_ref().unwrap_or(&rust_name).clone();
+        let (kind, luau_name) = match (attributes.method, attributes.get, attributes.set) {
+            (Some(method), None, None) => (SharedImportKind::Method, method),
+            (None, Some(property), None) => (SharedImportKind::Getter, property),
+            (None, None, Some(property)) => (SharedImportKind::Setter, property),
+            (None, None, None) => (
+                SharedImportKind::Function,
+                attributes.name.unwrap_or_else(|| rust_name.clone()),
+            ),
+            _ => panic!("#[luau] imports can only use one of `method`, `get` or `set`"),
+        };
+
         let describe_name = new_symbol_name(&rust_name);
         let export_name = new_symbol_name(&rust_name);
-        let arguments = self.sig.inputs.iter().map(type_from_arg).collect();
+        let arguments: Vec<_> = self.sig.inputs.iter().map(type_from_arg).collect();
+        let has_output = !matches!(self.sig.output, ReturnType::Default);
+
+        // When a namespace is specified, it is used as the object instead of the first argument.
+        let object_count = if namespace.is_some() { 0 } else { 1 };
+
+        match kind {
+            SharedImportKind::Method if arguments.len() < object_count => {
+                panic!("#[luau(method)] imports require the object as their first argument")
+            }
+            SharedImportKind::Getter if arguments.len() != object_count || !has_output => {
+                panic!("#[luau(get)] imports must take the object and return the property value")
+            }
+            SharedImportKind::Setter if arguments.len() != object_count + 1 || has_output => {
+                panic!("#[luau(set)] imports must take the object and the new value, and return nothing")
+            }
+            _ => {}
+        }
+
         let return_type = match &self.sig.output {
             ReturnType::Default => None,
             ReturnType::Type(_, ty) => Some(*ty.clone()),
@@ -54,6 +94,8 @@ impl Expand for ForeignItemFn {
         ctx.imports.push(ContextImport {
             namespace,
             import_kind: ImportKind::Function(ImportFunction {
+                vis: self.vis.clone(),
+                kind,
                 rust_name,
                 luau_name,
                 describe_name,
@@ -65,10 +107,23 @@ impl Expand for ForeignItemFn {
     }
 }
 
+impl Expand for ForeignItemType {
+    fn expand(&self, ctx: &mut Context) {
+        let mut attrs = self.attrs.clone();
+        attrs.retain(|attr| !attr.path().is_ident("luau"));
+
+        ctx.types.push(ImportType {
+            attrs,
+            vis: self.vis.clone(),
+            ident: self.ident.clone(),
+        });
+    }
+}
+
 impl Expand for ItemFn {
     fn expand(&self, ctx: &mut Context) {
         let attributes = &ctx.attributes;
-        let item = self.clone();
+        let mut item = self.clone();
         let rust_name = item.sig.ident.to_string();
         let export_name = new_symbol_name(&rust_name);
         if matches!(attributes.main, Some(true)) {
@@ -81,20 +136,208 @@ impl Expand for ItemFn {
 
         let describe_name = new_symbol_name(&rust_name);
         let luau_name = attributes.name.as_ref().unwrap_or(&rust_name).clone();
-        let arguments = item.sig.inputs.iter().map(type_from_arg).collect();
+        let (arguments, lossy) = expand_arguments(&mut item.sig.inputs, None);
         let return_type = match &item.sig.output {
             ReturnType::Type(_, ty) => Some(*ty.clone()),
             ReturnType::Default => None,
         };
 
-        ctx.exports.push(ContextExport::Function(ExportFunction {
+        let ident = &item.sig.ident;
+        let invoke = quote! { #ident };
+
+        ctx.exports.push(ContextExport::Function(
+            Box::new(item),
+            ExportFunction {
+                export_name,
+                describe_name,
+                rust_name,
+                luau_name,
+                class: None,
+                invoke,
+                return_type,
+                arguments,
+                lossy,
+            },
+        ));
+    }
+}
+
+impl Expand for ItemStruct {
+    fn expand(&self, ctx: &mut Context) {
+        let rust_name = self.ident.to_string();
+        let luau_name = ctx.attributes.name.as_ref().unwrap_or(&rust_name).clone();
+        let drop_name = new_symbol_name(format!("{rust_name}_drop"));
+
+        ctx.exports.push(ContextExport::Class(ExportClass {
+            item: self.clone(),
+            rust_name,
+            luau_name,
+            drop_name,
+        }));
+    }
+}
+
+impl Expand for ItemImpl {
+    fn expand(&self, ctx: &mut Context) {
+        let mut item = self.clone();
+        let self_ty = &*self.self_ty;
+        let Type::Path(self_path) = self_ty else {
+            panic!("#[luau] can only be applied to impl blocks of named types");
+        };
+
+        let class = self_path.path.segments.last().unwrap().ident.to_string();
+        let mut methods = Vec::new();
+
+        for impl_item in &mut item.items {
+            let ImplItem::Fn(method) = impl_item else {
+                continue;
+            };
+
+            // The method attributes are consumed here, so they must be removed before the impl block is emitted.
+            let attributes = ParsedAttributes::fetch(&method.attrs);
+            method.attrs.retain(|attr| !attr.path().is_ident("luau"));
+
+            // Only public methods are exposed, which allows private helpers in the same impl block.
+            if !matches!(method.vis, Visibility::Public(_)) {
+                continue;
+            }
+
+            let ident = &method.sig.ident;
+            let rust_name = ident.to_string();
+            let luau_name = match (attributes.name, attributes.constructor) {
+                (Some(name), _) => name,
+                (None, Some(true)) => "new".to_string(),
+                (None, _) => rust_name.clone(),
+            };
+
+            let describe_name = new_symbol_name(&rust_name);
+            let export_name = new_symbol_name(&rust_name);
+            let (arguments, lossy) = expand_arguments(&mut method.sig.inputs, Some(self_ty));
+
+            let return_type = match &method.sig.output {
+                ReturnType::Type(_, ty) => Some(replace_self(*ty.clone(), self_ty)),
+                ReturnType::Default => None,
+            };
+
+            methods.push(ExportFunction {
+                rust_name,
+                luau_name,
+                export_name,
+                describe_name,
+                class: Some(class.clone()),
+                invoke: quote! { <#self_ty>::#ident },
+                arguments,
+                lossy,
+                return_type,
+            });
+        }
+
+        ctx.exports.push(ContextExport::Impl(item, methods));
+    }
+}
+
+impl Expand for ItemEnum {
+    fn expand(&self, ctx: &mut Context) {
+        let mut item = self.clone();
+        let rust_name = self.ident.to_string();
+        let luau_name = ctx.attributes.name.as_ref().unwrap_or(&rust_name).clone();
+        let kind = match ctx.attributes.number {
+            Some(true) => SharedEnumKind::Number,
+            _ => SharedEnumKind::String,
+        };
+
+        let mut variants = Vec::new();
+        let mut discriminant = 0;
+
+        for variant in &mut item.variants {
+            // The variant attributes are consumed here, so they must be removed before the enum is emitted.
+            let attributes = ParsedAttributes::fetch(&variant.attrs);
+            variant.attrs.retain(|attr| !attr.path().is_ident("luau"));
+
+            if !matches!(variant.fields, Fields::Unit) {
+                panic!("#[luau] enums can only contain unit variants");
+            }
+
+            if let Some((_, expr)) = &variant.discriminant {
+                discriminant = match parse_discriminant(expr) {
+                    Some(value) => value,
+                    None if kind == SharedEnumKind::Number => {
+                        panic!("#[luau(number)] enums require integer literal discriminants")
+                    }
+                    None => discriminant,
+                };
+            }
+
+            variants.push(ExportEnumVariant {
+                ident: variant.ident.clone(),
+                luau_name: attributes.name.unwrap_or_else(|| variant.ident.to_string()),
+                discriminant,
+            });
+
+            discriminant += 1;
+        }
+
+        ctx.exports.push(ContextExport::Enum(ExportEnum {
             item,
-            export_name,
-            describe_name,
             rust_name,
             luau_name,
-            return_type,
-            arguments,
+            kind,
+            variants,
         }));
     }
 }
+
+/// Fetches the argument types of an exported function, consuming the `#[luau]` attributes of its parameters.
+///
+/// Strings marked with `#[luau(lossy)]` are received as `LuauBytes`, so invalid UTF-8 can be replaced in Rust.
+fn expand_arguments(
+    inputs: &mut Punctuated<FnArg, Comma>,
+    self_ty: Option<&Type>,
+) -> (Vec<Type>, Vec<bool>) {
+    let mut arguments = Vec::new();
+    let mut lossy = Vec::new();
+
+    for input in inputs {
+        let mut ty = type_from_arg(input);
+        if let Some(self_ty) = self_ty {
+            ty = replace_self(ty, self_ty);
+        }
+
+        let FnArg::Typed(input) = input else {
+            arguments.push(ty);
+            lossy.push(false);
+            continue;
+        };
+
+        let attributes = ParsedAttributes::fetch(&input.attrs);
+        input.attrs.retain(|attr| !attr.path().is_ident("luau"));
+
+        let is_lossy = matches!(attributes.lossy, Some(true));
+        if is_lossy {
+            if !matches!(&ty, Type::Path(path) if path.path.is_ident("String")) {
+                panic!("#[luau(lossy)] can only be used on `String` parameters");
+            }
+
+            ty = parse_quote! { roblox_rs::prelude::LuauBytes };
+        }
+
+        arguments.push(ty);
+        lossy.push(is_lossy);
+    }
+
+    (arguments, lossy)
+}
+
+fn parse_discriminant(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_discriminant(expr).map(|value| -value),
+        _ => None,
+    }
+}
@@ -1,6 +1,6 @@
 mod context;
 mod expand;
-mod parse;
+pub(crate) mod parse;
 pub mod symbol;
 
 use context::Context;
@@ -9,11 +9,15 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use roblox_rs_shared_context::shared_context::{SharedContext, SharedIntrinsic};
 use symbol::new_symbol_name;
-use syn::{FnArg, Item, Type};
+use syn::{
+    visit_mut::{self, VisitMut},
+    FnArg, Item, Type,
+};
 
 pub fn expand_attribute(attrs: TokenStream, input: TokenStream) -> TokenStream {
     let mut context = Context {
         imports: Vec::new(),
+        types: Vec::new(),
         exports: Vec::new(),
         main_fns: Vec::new(),
         attributes: syn::parse2(attrs).unwrap(),
@@ -65,6 +69,25 @@ pub fn expand_intrinsic(_attr: TokenStream, input: TokenStream) -> TokenStream {
 fn type_from_arg(ty: &FnArg) -> Type {
     match ty {
         FnArg::Typed(ty) => *ty.ty.clone(),
-        FnArg::Receiver(_) => unimplemented!(),
+        FnArg::Receiver(receiver) => *receiver.ty.clone(),
     }
 }
+
+/// Replaces any usage of `Self` in the specified type, so it can be used outside of an impl block.
+fn replace_self(mut ty: Type, self_ty: &Type) -> Type {
+    struct ReplaceSelf<'a>(&'a Type);
+
+    impl VisitMut for ReplaceSelf<'_> {
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            match ty {
+                Type::Path(path) if path.qself.is_none() && path.path.is_ident("Self") => {
+                    *ty = self.0.clone();
+                }
+                _ => visit_mut::visit_type_mut(self, ty),
+            }
+        }
+    }
+
+    ReplaceSelf(self_ty).visit_type_mut(&mut ty);
+    ty
+}
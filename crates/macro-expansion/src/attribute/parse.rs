@@ -9,9 +9,18 @@ use syn::{
 
 #[derive(Default, Debug)]
 pub struct ParsedAttributes {
+    /// A Luau path which imports are resolved against, e.g `math` calls `math.floor(...)`.
+    ///
+    /// Functions of an instance are called as methods, e.g `game.Workspace` calls `game.Workspace:GetChildren()`.
     pub namespace: Option<String>,
     pub name: Option<String>,
     pub main: Option<bool>,
+    pub constructor: Option<bool>,
+    pub method: Option<String>,
+    pub get: Option<String>,
+    pub set: Option<String>,
+    pub number: Option<bool>,
+    pub lossy: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -50,6 +59,12 @@ impl Parse for ParsedAttributes {
                 namespace => String
                 name => String
                 main => Boolean
+                constructor => Boolean
+                method => String
+                get => String
+                set => String
+                number => Boolean
+                lossy => Boolean
             )
         }
 
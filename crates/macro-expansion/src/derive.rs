@@ -0,0 +1,159 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields};
+
+use crate::attribute::parse::ParsedAttributes;
+
+/// Expands `#[derive(LuauTable)]`, which passes a struct by value as a pointer to its fields in linear memory.
+///
+/// The fields are stored using their ABI primitives in a flat `#[repr(C)]` struct,
+/// which matches the layout the CLI computes for the fields of a table.
+pub fn expand_luau_table(input: TokenStream) -> TokenStream {
+    let input = syn::parse2::<DeriveInput>(input).unwrap();
+    let ident = &input.ident;
+    let rust_name = ident.to_string();
+
+    if !input.generics.params.is_empty() {
+        panic!("#[derive(LuauTable)] can't be used on generic structs");
+    }
+
+    let Data::Struct(data) = &input.data else {
+        panic!("#[derive(LuauTable)] can only be used on structs");
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        panic!("#[derive(LuauTable)] can only be used on structs with named fields");
+    };
+
+    // Tables are passed as a pointer to their fields, which can't be allocated without any fields.
+    if fields.named.is_empty() {
+        panic!("#[derive(LuauTable)] can't be used on structs without fields");
+    }
+
+    let name_len = rust_name.len() as u32;
+    let name_bytes = rust_name.bytes().map(u32::from);
+    let field_count = fields.named.len() as u32;
+
+    let mut describes = Vec::new();
+    let mut into_prims = Vec::new();
+    let mut ref_prims = Vec::new();
+    let mut from_prims = Vec::new();
+    let mut splits = Vec::new();
+    let mut ref_splits = Vec::new();
+    let mut joins = Vec::new();
+
+    for (i, field) in fields.named.iter().enumerate() {
+        let field_ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let attributes = ParsedAttributes::fetch(&field.attrs);
+        let luau_name = attributes.name.unwrap_or_else(|| field_ident.to_string());
+        let luau_name_len = luau_name.len() as u32;
+        let luau_name_bytes = luau_name.bytes().map(u32::from);
+
+        describes.push(quote! {
+            describe(#luau_name_len);
+            #(describe(#luau_name_bytes);)*
+            <#ty as WasmDescribe>::describe();
+        });
+
+        let prims: Vec<_> = (1..=4)
+            .map(|prim| format_ident!("field{i}_{prim}"))
+            .collect();
+        let prim_types: Vec<_> = (1..=4).map(|prim| format_ident!("Prim{prim}")).collect();
+
+        into_prims.push(quote! {
+            #(#prims: <<#ty as WasmIntoAbi>::Abi as WasmAbi>::#prim_types,)*
+        });
+
+        ref_prims.push(quote! {
+            #(#prims: <<#ty as WasmRefIntoAbi>::Abi as WasmAbi>::#prim_types,)*
+        });
+
+        from_prims.push(quote! {
+            #(#prims: <<#ty as WasmFromAbi>::Abi as WasmAbi>::#prim_types,)*
+        });
+
+        splits.push(quote! {
+            let (#(#prims),*) = WasmAbi::split(WasmIntoAbi::into_abi(self.#field_ident));
+        });
+
+        ref_splits.push(quote! {
+            let (#(#prims),*) = WasmAbi::split(WasmRefIntoAbi::ref_into_abi(&self.#field_ident));
+        });
+
+        joins.push(quote! {
+            #field_ident: <#ty as WasmFromAbi>::from_abi(
+                <<#ty as WasmFromAbi>::Abi as WasmAbi>::join(#(layout.#prims),*)
+            ),
+        });
+    }
+
+    let prim_names: Vec<_> = (0..fields.named.len())
+        .flat_map(|i| (1..=4).map(move |prim| format_ident!("field{i}_{prim}")))
+        .collect();
+
+    quote! {
+        const _: () = {
+            use roblox_rs::internal::*;
+
+            #[repr(C)]
+            struct IntoLayout {
+                #(#into_prims)*
+            }
+
+            #[repr(C)]
+            struct RefLayout {
+                #(#ref_prims)*
+            }
+
+            #[repr(C)]
+            struct FromLayout {
+                #(#from_prims)*
+            }
+
+            impl WasmDescribe for #ident {
+                #[inline(always)]
+                fn describe() {
+                    describe(TABLE);
+                    describe(#name_len);
+                    #(describe(#name_bytes);)*
+                    describe(#field_count);
+                    #(#describes)*
+                }
+            }
+
+            impl WasmIntoAbi for #ident {
+                type Abi = u32;
+
+                fn into_abi(self) -> Self::Abi {
+                    #(#splits)*
+
+                    Box::into_raw(Box::new(IntoLayout { #(#prim_names),* })) as u32
+                }
+            }
+
+            // Borrowed tables only borrow their fields, Luau frees the layout once it has read them.
+            impl WasmRefIntoAbi for #ident {
+                type Abi = u32;
+
+                fn ref_into_abi(&self) -> Self::Abi {
+                    #(#ref_splits)*
+
+                    Box::into_raw(Box::new(RefLayout { #(#prim_names),* })) as u32
+                }
+            }
+
+            impl WasmFromAbi for #ident {
+                type Abi = u32;
+
+                unsafe fn from_abi(value: Self::Abi) -> Self {
+                    let layout = *Box::from_raw(value as *mut FromLayout);
+
+                    Self {
+                        #(#joins)*
+                    }
+                }
+            }
+        };
+    }
+}
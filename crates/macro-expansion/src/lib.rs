@@ -1,3 +1,4 @@
 pub mod attribute;
+pub mod derive;
 
 mod tests;
@@ -2,9 +2,10 @@
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::ItemForeignMod;
+use roblox_rs_shared_context::shared_context::SharedImportKind;
+use syn::{File, ItemForeignMod};
 
-use crate::attribute;
+use crate::{attribute, derive};
 
 #[test]
 fn attribute_expansion() {
@@ -27,3 +28,104 @@ fn attribute_expansion() {
     let result = attribute::expand_attribute(attribute, input);
     println!("{}", result);
 }
+
+#[test]
+fn class_expansion() {
+    let class = quote! {
+        pub struct Counter {
+            value: u32,
+        }
+    };
+
+    let methods = quote! {
+        impl Counter {
+            #[luau(constructor)]
+            pub fn new(value: u32) -> Self {
+                Self { value }
+            }
+
+            pub fn get(&self) -> u32 {
+                self.value
+            }
+
+            fn private(&mut self) {}
+        }
+    };
+
+    for input in [class, methods] {
+        let result = attribute::expand_attribute(TokenStream::new(), input);
+        let result = syn::parse2::<File>(result).expect("expansion should be valid Rust");
+
+        for item in result.items {
+            if let syn::Item::Impl(item) = item {
+                for impl_item in item.items {
+                    if let syn::ImplItem::Fn(method) = impl_item {
+                        assert!(method
+                            .attrs
+                            .iter()
+                            .all(|attr| !attr.path().is_ident("luau")));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn instance_namespace_function() {
+    let imports = quote! {
+        extern "C" {
+            fn GetChildren() -> Vec<LuauValue>;
+        }
+    };
+
+    let result = attribute::expand_attribute(quote!(namespace = "game.Workspace"), imports);
+    let result = syn::parse2::<File>(result).expect("expansion should be valid Rust");
+
+    // The shared context is embedded as a static, which the CLI reads to generate the call.
+    let encoded = result
+        .items
+        .iter()
+        .find_map(|item| match item {
+            syn::Item::Const(item) => match &*item.expr {
+                syn::Expr::Block(block) => block.block.stmts.iter().find_map(|stmt| match stmt {
+                    syn::Stmt::Item(syn::Item::Static(item)) => Some(item.expr.clone()),
+                    _ => None,
+                }),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("expansion should embed the shared context");
+
+    let syn::Expr::Array(bytes) = *encoded else {
+        panic!("shared context should be a byte array");
+    };
+
+    let bytes: Vec<u8> = bytes
+        .elems
+        .iter()
+        .map(|v| match v {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(int),
+                ..
+            }) => int.base10_parse().unwrap(),
+            _ => panic!("shared context should only contain bytes"),
+        })
+        .collect();
+
+    let context = roblox_rs_shared_context::decode(&bytes);
+    let import = &context.imports[0];
+
+    assert_eq!(import.luau_name, "GetChildren");
+    assert_eq!(import.kind, SharedImportKind::Function);
+    assert_eq!(import.namespace.as_deref(), Some("game.Workspace"));
+}
+
+#[test]
+#[should_panic(expected = "can't be used on structs without fields")]
+fn empty_table_derive() {
+    derive::expand_luau_table(quote! {
+        struct Empty {}
+    });
+}
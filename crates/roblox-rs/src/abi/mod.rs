@@ -2,6 +2,10 @@ pub mod wasm_abi;
 pub mod wasm_describe;
 pub mod wasm_from_abi;
 pub mod wasm_into_abi;
+pub mod wasm_map;
 pub mod wasm_primitive;
+pub mod wasm_ref_from_abi;
+pub mod wasm_ref_into_abi;
 pub mod wasm_return_abi;
 pub mod wasm_slice;
+pub mod wasm_tuple;
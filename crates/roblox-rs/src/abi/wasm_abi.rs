@@ -64,3 +64,63 @@ impl<T: WasmAbi<Prim4 = ()>> WasmAbi for Option<T> {
         }
     }
 }
+
+/// A result sent over the ABI, errors are a pointer to a boxed `WasmSlice` containing the error message.
+pub enum WasmResult<T> {
+    Ok(T),
+    Err(u32),
+}
+
+impl<T: WasmAbi<Prim4 = ()>> WasmAbi for WasmResult<T> {
+    type Prim1 = u32;
+    type Prim2 = T::Prim1;
+    type Prim3 = T::Prim2;
+    type Prim4 = T::Prim3;
+
+    fn join(
+        prim1: Self::Prim1,
+        prim2: Self::Prim2,
+        prim3: Self::Prim3,
+        prim4: Self::Prim4,
+    ) -> Self {
+        match prim1 {
+            0 => WasmResult::Ok(T::join(prim2, prim3, prim4, ())),
+            error => WasmResult::Err(error),
+        }
+    }
+
+    fn split(self) -> (Self::Prim1, Self::Prim2, Self::Prim3, Self::Prim4) {
+        match self {
+            WasmResult::Ok(value) => {
+                let (prim1, prim2, prim3, _) = T::split(value);
+                (0, prim1, prim2, prim3)
+            }
+            WasmResult::Err(error) => (
+                error,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ),
+        }
+    }
+}
+
+/// A pair of single primitives, e.g the halves of a `u64`. Tuples returned by functions use `WasmTuple` instead.
+impl<A, B> WasmAbi for (A, B)
+where
+    A: WasmAbi<Prim2 = (), Prim3 = (), Prim4 = ()>,
+    B: WasmAbi<Prim2 = (), Prim3 = (), Prim4 = ()>,
+{
+    type Prim1 = A::Prim1;
+    type Prim2 = B::Prim1;
+    type Prim3 = ();
+    type Prim4 = ();
+
+    fn join(prim1: Self::Prim1, prim2: Self::Prim2, _: (), _: ()) -> Self {
+        (A::join(prim1, (), (), ()), B::join(prim2, (), (), ()))
+    }
+
+    fn split(self) -> (Self::Prim1, Self::Prim2, Self::Prim3, Self::Prim4) {
+        (self.0.split().0, self.1.split().0, (), ())
+    }
+}
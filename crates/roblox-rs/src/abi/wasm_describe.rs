@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, HashMap};
+
 use crate::ffi::describe;
 
 pub trait WasmDescribe {
@@ -22,6 +24,25 @@ pub const EXTERNREF: u32 = 14;
 pub const STRING: u32 = 15;
 pub const SLICE: u32 = 16;
 pub const VECTOR: u32 = 17;
+pub const CLASS: u32 = 18;
+pub const RESULT: u32 = 19;
+pub const TUPLE: u32 = 20;
+pub const VARIADIC: u32 = 21;
+pub const ENUM: u32 = 22;
+pub const TABLE: u32 = 23;
+pub const MAP: u32 = 24;
+pub const CHAR: u32 = 25;
+pub const U64: u32 = 26;
+pub const I64: u32 = 27;
+pub const BUFFER: u32 = 28;
+pub const BYTES: u32 = 29;
+pub const ENUM_ITEM: u32 = 30;
+pub const VECTOR2: u32 = 31;
+pub const VECTOR3: u32 = 32;
+pub const CFRAME: u32 = 33;
+pub const COLOR3: u32 = 34;
+pub const UDIM2: u32 = 35;
+pub const RECT: u32 = 36;
 
 macro_rules! simple {
 	($($t:ty:$e:expr;)*) => {
@@ -45,6 +66,11 @@ simple!(
     i32: I32;
     f32: F32;
     f64: F64;
+    u64: U64;
+    i64: I64;
+    usize: U32;
+    isize: I32;
+    char: CHAR;
     bool: BOOLEAN;
     str: STRING;
     String: STRING;
@@ -77,6 +103,22 @@ impl<T: WasmDescribe> WasmDescribe for Box<[T]> {
     }
 }
 
+impl<K: WasmDescribe, V: WasmDescribe, S> WasmDescribe for HashMap<K, V, S> {
+    fn describe() {
+        describe(MAP);
+        K::describe();
+        V::describe();
+    }
+}
+
+impl<K: WasmDescribe, V: WasmDescribe> WasmDescribe for BTreeMap<K, V> {
+    fn describe() {
+        describe(MAP);
+        K::describe();
+        V::describe();
+    }
+}
+
 impl<T: WasmDescribe> WasmDescribe for [T] {
     fn describe() {
         describe(SLICE);
@@ -114,3 +156,32 @@ impl<T: WasmDescribe> WasmDescribe for Option<T> {
         T::describe();
     }
 }
+
+impl<T: WasmDescribe, E> WasmDescribe for Result<T, E> {
+    #[inline(always)]
+    fn describe() {
+        describe(RESULT);
+        T::describe();
+    }
+}
+
+macro_rules! tuple_describe {
+    ($($count:literal => ($($t:ident)*);)*) => {
+        $(
+            impl<$($t: WasmDescribe),*> WasmDescribe for ($($t,)*) {
+                #[inline(always)]
+                fn describe() {
+                    describe(TUPLE);
+                    describe($count);
+                    $($t::describe();)*
+                }
+            }
+        )*
+    };
+}
+
+tuple_describe!(
+    2 => (A B);
+    3 => (A B C);
+    4 => (A B C D);
+);
@@ -1,6 +1,11 @@
-use crate::internal::WasmDescribe;
+use crate::{internal::WasmDescribe, luau_bytes::LuauBytes, luau_error::LuauError};
 
-use super::{wasm_abi::WasmAbi, wasm_primitive::WasmPrimitive};
+use super::{
+    wasm_abi::{WasmAbi, WasmResult},
+    wasm_primitive::WasmPrimitive,
+    wasm_slice::WasmSlice,
+    wasm_tuple::WasmTuple,
+};
 
 pub trait WasmFromAbi: WasmDescribe {
     type Abi: WasmAbi;
@@ -10,7 +15,7 @@ pub trait WasmFromAbi: WasmDescribe {
     unsafe fn from_abi(value: Self::Abi) -> Self;
 }
 
-impl<T: WasmPrimitive> WasmFromAbi for T {
+impl<T: WasmPrimitive + WasmDescribe> WasmFromAbi for T {
     type Abi = T;
 
     #[inline(always)]
@@ -28,3 +33,94 @@ impl<T: WasmFromAbi<Abi: WasmAbi<Prim4 = ()>>> WasmFromAbi for Option<T> {
         value.map(|v| T::from_abi(v))
     }
 }
+
+impl WasmFromAbi for bool {
+    type Abi = u32;
+
+    #[inline(always)]
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        value != 0
+    }
+}
+
+impl WasmFromAbi for u64 {
+    type Abi = (u32, u32);
+
+    #[inline(always)]
+    unsafe fn from_abi((low, high): Self::Abi) -> Self {
+        low as u64 | (high as u64) << 32
+    }
+}
+
+impl WasmFromAbi for i64 {
+    type Abi = (u32, i32);
+
+    #[inline(always)]
+    unsafe fn from_abi((low, high): Self::Abi) -> Self {
+        low as i64 | (high as i64) << 32
+    }
+}
+
+impl WasmFromAbi for usize {
+    type Abi = u32;
+
+    #[inline(always)]
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        value as usize
+    }
+}
+
+impl WasmFromAbi for isize {
+    type Abi = i32;
+
+    #[inline(always)]
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        value as isize
+    }
+}
+
+impl WasmFromAbi for char {
+    type Abi = u32;
+
+    #[inline(always)]
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        char::from_u32(value).expect("invalid char")
+    }
+}
+
+impl<T: WasmFromAbi<Abi: WasmAbi<Prim4 = ()>>> WasmFromAbi for Result<T, LuauError> {
+    type Abi = WasmResult<T::Abi>;
+
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        match value {
+            WasmResult::Ok(value) => Ok(T::from_abi(value)),
+            WasmResult::Err(error) => {
+                // Error messages aren't validated by Luau, so any invalid UTF-8 is replaced instead.
+                let message = *Box::from_raw(error as *mut WasmSlice);
+                let message = LuauBytes::from_abi(message).into_string_lossy();
+                Err(LuauError::new(message))
+            }
+        }
+    }
+}
+
+macro_rules! tuple_from_abi {
+    ($(($($t:ident $index:tt)*) [$($unused:tt)*];)*) => {
+        $(
+            impl<$($t: WasmFromAbi),*> WasmFromAbi for ($($t,)*) {
+                type Abi = WasmTuple<$($t::Abi,)* $($unused,)*>;
+
+                unsafe fn from_abi(value: Self::Abi) -> Self {
+                    let value = value.join();
+                    ($($t::from_abi(value.$index),)*)
+                }
+            }
+        )*
+    };
+}
+
+tuple_from_abi!(
+    (A 0 B 1) [() ()];
+    (A 0 B 1 C 2) [()];
+    (A 0 B 1 C 2 D 3) [];
+);
@@ -1,6 +1,12 @@
+use std::fmt::Display;
+
 use crate::internal::WasmDescribe;
 
-use super::{wasm_abi::WasmAbi, wasm_primitive::WasmPrimitive};
+use super::{
+    wasm_abi::{WasmAbi, WasmResult},
+    wasm_primitive::WasmPrimitive,
+    wasm_tuple::WasmTuple,
+};
 
 pub trait WasmIntoAbi: WasmDescribe {
     type Abi: WasmAbi;
@@ -8,7 +14,7 @@ pub trait WasmIntoAbi: WasmDescribe {
     fn into_abi(self) -> Self::Abi;
 }
 
-impl<T: WasmPrimitive> WasmIntoAbi for T {
+impl<T: WasmPrimitive + WasmDescribe> WasmIntoAbi for T {
     type Abi = T;
 
     #[inline(always)]
@@ -26,3 +32,92 @@ impl<T: WasmIntoAbi<Abi: WasmAbi<Prim4 = ()>>> WasmIntoAbi for Option<T> {
         self.map(|v| v.into_abi())
     }
 }
+
+impl WasmIntoAbi for bool {
+    type Abi = u32;
+
+    #[inline(always)]
+    fn into_abi(self) -> Self::Abi {
+        self as u32
+    }
+}
+
+// 64-bit integers are split into their low and high halves, since Luau can't represent them as a single primitive.
+impl WasmIntoAbi for u64 {
+    type Abi = (u32, u32);
+
+    #[inline(always)]
+    fn into_abi(self) -> Self::Abi {
+        (self as u32, (self >> 32) as u32)
+    }
+}
+
+impl WasmIntoAbi for i64 {
+    type Abi = (u32, i32);
+
+    #[inline(always)]
+    fn into_abi(self) -> Self::Abi {
+        (self as u32, (self >> 32) as i32)
+    }
+}
+
+impl WasmIntoAbi for usize {
+    type Abi = u32;
+
+    #[inline(always)]
+    fn into_abi(self) -> Self::Abi {
+        self as u32
+    }
+}
+
+impl WasmIntoAbi for isize {
+    type Abi = i32;
+
+    #[inline(always)]
+    fn into_abi(self) -> Self::Abi {
+        self as i32
+    }
+}
+
+impl WasmIntoAbi for char {
+    type Abi = u32;
+
+    #[inline(always)]
+    fn into_abi(self) -> Self::Abi {
+        self as u32
+    }
+}
+
+impl<T: WasmIntoAbi<Abi: WasmAbi<Prim4 = ()>>, E: Display> WasmIntoAbi for Result<T, E> {
+    type Abi = WasmResult<T::Abi>;
+
+    fn into_abi(self) -> Self::Abi {
+        match self {
+            Ok(value) => WasmResult::Ok(value.into_abi()),
+            Err(error) => {
+                let message = Box::new(error.to_string().into_abi());
+                WasmResult::Err(Box::into_raw(message) as u32)
+            }
+        }
+    }
+}
+
+macro_rules! tuple_into_abi {
+    ($(($($t:ident $index:tt)*) [$($unused:tt)*];)*) => {
+        $(
+            impl<$($t: WasmIntoAbi),*> WasmIntoAbi for ($($t,)*) {
+                type Abi = WasmTuple<$($t::Abi,)* $($unused,)*>;
+
+                fn into_abi(self) -> Self::Abi {
+                    WasmTuple::new($(self.$index.into_abi(),)* $($unused,)*)
+                }
+            }
+        )*
+    };
+}
+
+tuple_into_abi!(
+    (A 0 B 1) [() ()];
+    (A 0 B 1 C 2) [()];
+    (A 0 B 1 C 2 D 3) [];
+);
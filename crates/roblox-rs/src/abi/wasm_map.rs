@@ -0,0 +1,109 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::internal::{WasmFromAbi, WasmIntoAbi};
+
+use super::{wasm_abi::WasmAbi, wasm_slice::WasmSlice};
+
+/// A key-value pair of a map, the primitives are flattened so the layout matches the one the CLI computes.
+#[repr(C)]
+pub struct WasmMapEntry<K: WasmAbi, V: WasmAbi> {
+    key1: K::Prim1,
+    key2: K::Prim2,
+    key3: K::Prim3,
+    key4: K::Prim4,
+    value1: V::Prim1,
+    value2: V::Prim2,
+    value3: V::Prim3,
+    value4: V::Prim4,
+}
+
+impl<K: WasmAbi, V: WasmAbi> WasmMapEntry<K, V> {
+    pub(crate) fn new(key: K, value: V) -> Self {
+        let (key1, key2, key3, key4) = key.split();
+        let (value1, value2, value3, value4) = value.split();
+
+        Self {
+            key1,
+            key2,
+            key3,
+            key4,
+            value1,
+            value2,
+            value3,
+            value4,
+        }
+    }
+
+    fn join(self) -> (K, V) {
+        (
+            K::join(self.key1, self.key2, self.key3, self.key4),
+            V::join(self.value1, self.value2, self.value3, self.value4),
+        )
+    }
+}
+
+fn into_entries<K: WasmIntoAbi, V: WasmIntoAbi>(
+    map: impl IntoIterator<Item = (K, V)>,
+) -> WasmSlice {
+    let entries: Box<[_]> = map
+        .into_iter()
+        .map(|(key, value)| WasmMapEntry::new(key.into_abi(), value.into_abi()))
+        .collect();
+    let len = entries.len();
+    let ptr = Box::into_raw(entries) as *mut u8;
+
+    WasmSlice { ptr, len }
+}
+
+unsafe fn from_entries<K: WasmFromAbi, V: WasmFromAbi>(
+    value: WasmSlice,
+) -> impl Iterator<Item = (K, V)> {
+    let ptr = value.ptr as *mut WasmMapEntry<K::Abi, V::Abi>;
+
+    Vec::from_raw_parts(ptr, value.len, value.len)
+        .into_iter()
+        .map(|entry| {
+            let (key, value) = entry.join();
+            (K::from_abi(key), V::from_abi(value))
+        })
+}
+
+impl<K: WasmIntoAbi, V: WasmIntoAbi, S> WasmIntoAbi for HashMap<K, V, S> {
+    type Abi = WasmSlice;
+
+    fn into_abi(self) -> Self::Abi {
+        into_entries(self)
+    }
+}
+
+impl<K, V, S> WasmFromAbi for HashMap<K, V, S>
+where
+    K: WasmFromAbi + Eq + Hash,
+    V: WasmFromAbi,
+    S: BuildHasher + Default,
+{
+    type Abi = WasmSlice;
+
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        from_entries(value).collect()
+    }
+}
+
+impl<K: WasmIntoAbi, V: WasmIntoAbi> WasmIntoAbi for BTreeMap<K, V> {
+    type Abi = WasmSlice;
+
+    fn into_abi(self) -> Self::Abi {
+        into_entries(self)
+    }
+}
+
+impl<K: WasmFromAbi + Ord, V: WasmFromAbi> WasmFromAbi for BTreeMap<K, V> {
+    type Abi = WasmSlice;
+
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        from_entries(value).collect()
+    }
+}
@@ -1,8 +1,6 @@
-use crate::internal::WasmDescribe;
-
 /// # Safety
 /// This can only be implemented on types that are safe to pass through ABI.
-pub unsafe trait WasmPrimitive: Default + WasmDescribe {}
+pub unsafe trait WasmPrimitive: Copy + Default {}
 
 macro_rules! impl_primitive {
     ($($id:ty),*) => {
@@ -0,0 +1,29 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::internal::WasmDescribe;
+
+use super::wasm_abi::WasmAbi;
+
+/// Borrows a value received over the ABI for the duration of a call.
+pub trait WasmRefFromAbi: WasmDescribe {
+    type Abi: WasmAbi;
+
+    /// Keeps the borrowed value alive, and cleans it up once the call has finished.
+    type Anchor: Deref<Target = Self>;
+
+    /// # Safety
+    /// This function is only safe if used on values returned by the Luau runtime.
+    unsafe fn ref_from_abi(value: Self::Abi) -> Self::Anchor;
+}
+
+/// Mutably borrows a value received over the ABI for the duration of a call.
+pub trait WasmRefMutFromAbi: WasmDescribe {
+    type Abi: WasmAbi;
+
+    /// Keeps the borrowed value alive, and cleans it up once the call has finished.
+    type Anchor: DerefMut<Target = Self>;
+
+    /// # Safety
+    /// This function is only safe if used on values returned by the Luau runtime.
+    unsafe fn ref_mut_from_abi(value: Self::Abi) -> Self::Anchor;
+}
@@ -0,0 +1,161 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::internal::{WasmDescribe, WasmIntoAbi};
+
+use super::{
+    wasm_abi::WasmAbi, wasm_map::WasmMapEntry, wasm_primitive::WasmPrimitive, wasm_slice::WasmSlice,
+};
+
+/// Converts a borrowed value into its ABI, so it can be passed to Luau without being cloned.
+///
+/// Luau only reads borrowed values, so it never frees anything the value owns.
+pub trait WasmRefIntoAbi: WasmDescribe {
+    type Abi: WasmAbi;
+
+    fn ref_into_abi(&self) -> Self::Abi;
+
+    /// Converts each element into a temporary array of ABI values, which Luau frees once it has read them.
+    fn slice_into_abi(slice: &[Self]) -> WasmSlice
+    where
+        Self: Sized,
+    {
+        into_temporary(slice.iter().map(Self::ref_into_abi))
+    }
+}
+
+fn into_temporary<T>(values: impl Iterator<Item = T>) -> WasmSlice {
+    let values: Box<[T]> = values.collect();
+    let len = values.len();
+    let ptr = Box::into_raw(values) as *mut u8;
+
+    WasmSlice { ptr, len }
+}
+
+/// Slices which already have the layout Luau expects are lent as they are, so Luau must not free them.
+pub(crate) fn lend<T>(slice: &[T]) -> WasmSlice {
+    WasmSlice {
+        ptr: slice.as_ptr() as *mut u8,
+        len: slice.len(),
+    }
+}
+
+impl<T: WasmPrimitive + WasmDescribe> WasmRefIntoAbi for T {
+    type Abi = T;
+
+    #[inline(always)]
+    fn ref_into_abi(&self) -> Self::Abi {
+        *self
+    }
+
+    fn slice_into_abi(slice: &[Self]) -> WasmSlice {
+        lend(slice)
+    }
+}
+
+// These are described as the primitive they're passed as, so their slices are lent too.
+impl WasmRefIntoAbi for usize {
+    type Abi = u32;
+
+    #[inline(always)]
+    fn ref_into_abi(&self) -> Self::Abi {
+        (*self).into_abi()
+    }
+
+    fn slice_into_abi(slice: &[Self]) -> WasmSlice {
+        lend(slice)
+    }
+}
+
+impl WasmRefIntoAbi for isize {
+    type Abi = i32;
+
+    #[inline(always)]
+    fn ref_into_abi(&self) -> Self::Abi {
+        (*self).into_abi()
+    }
+
+    fn slice_into_abi(slice: &[Self]) -> WasmSlice {
+        lend(slice)
+    }
+}
+
+macro_rules! copy_ref_into_abi {
+    ($($ty:ty),*) => {
+        $(
+            impl WasmRefIntoAbi for $ty {
+                type Abi = <$ty as WasmIntoAbi>::Abi;
+
+                #[inline(always)]
+                fn ref_into_abi(&self) -> Self::Abi {
+                    (*self).into_abi()
+                }
+            }
+        )*
+    };
+}
+
+copy_ref_into_abi!(bool, char, u64, i64);
+
+impl<T: WasmRefIntoAbi<Abi: WasmAbi<Prim4 = ()>>> WasmRefIntoAbi for Option<T> {
+    type Abi = Option<T::Abi>;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        self.as_ref().map(T::ref_into_abi)
+    }
+}
+
+impl WasmRefIntoAbi for String {
+    type Abi = WasmSlice;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        lend(self.as_bytes())
+    }
+}
+
+impl WasmRefIntoAbi for &str {
+    type Abi = WasmSlice;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        lend(self.as_bytes())
+    }
+}
+
+impl<T: WasmRefIntoAbi> WasmRefIntoAbi for Vec<T> {
+    type Abi = WasmSlice;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        T::slice_into_abi(self)
+    }
+}
+
+impl<T: WasmRefIntoAbi> WasmRefIntoAbi for Box<[T]> {
+    type Abi = WasmSlice;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        T::slice_into_abi(self)
+    }
+}
+
+fn into_ref_entries<'a, K: WasmRefIntoAbi + 'a, V: WasmRefIntoAbi + 'a>(
+    map: impl Iterator<Item = (&'a K, &'a V)>,
+) -> WasmSlice {
+    into_temporary(
+        map.map(|(key, value)| WasmMapEntry::new(key.ref_into_abi(), value.ref_into_abi())),
+    )
+}
+
+impl<K: WasmRefIntoAbi, V: WasmRefIntoAbi, S> WasmRefIntoAbi for HashMap<K, V, S> {
+    type Abi = WasmSlice;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        into_ref_entries(self.iter())
+    }
+}
+
+impl<K: WasmRefIntoAbi, V: WasmRefIntoAbi> WasmRefIntoAbi for BTreeMap<K, V> {
+    type Abi = WasmSlice;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        into_ref_entries(self.iter())
+    }
+}
@@ -1,11 +1,20 @@
-use crate::internal::{WasmFromAbi, WasmIntoAbi};
+use std::{
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+};
 
-use super::{wasm_abi::WasmAbi, wasm_primitive::WasmPrimitive};
+use crate::internal::{WasmDescribe, WasmFromAbi, WasmIntoAbi, WasmRefFromAbi, WasmRefMutFromAbi};
+
+use super::{
+    wasm_abi::WasmAbi,
+    wasm_primitive::WasmPrimitive,
+    wasm_ref_into_abi::{lend, WasmRefIntoAbi},
+};
 
 #[repr(C)]
 pub struct WasmSlice {
-    ptr: *mut u8,
-    len: usize,
+    pub(crate) ptr: *mut u8,
+    pub(crate) len: usize,
 }
 
 impl WasmAbi for WasmSlice {
@@ -37,27 +46,56 @@ impl WasmIntoAbi for String {
 impl WasmFromAbi for String {
     type Abi = <Vec<u8> as WasmFromAbi>::Abi;
 
+    // Luau strings are arbitrary bytes, and Luau's `utf8.len` accepts some sequences Rust doesn't, like surrogates.
+    // The runtime raises an error for invalid UTF-8 first, this panics if anything gets through.
     unsafe fn from_abi(value: Self::Abi) -> Self {
-        String::from_utf8_unchecked(<Vec<u8> as WasmFromAbi>::from_abi(value))
+        String::from_utf8(<Vec<u8> as WasmFromAbi>::from_abi(value))
+            .expect("expected a valid UTF-8 string")
     }
 }
 
-impl<T: WasmPrimitive> WasmIntoAbi for &[T] {
+impl<T: WasmRefIntoAbi> WasmIntoAbi for &[T] {
     type Abi = WasmSlice;
 
     fn into_abi(self) -> Self::Abi {
-        WasmSlice {
-            ptr: self.as_ptr() as *mut u8,
-            len: self.len(),
-        }
+        T::slice_into_abi(self)
     }
 }
 
-impl<T: WasmPrimitive> WasmIntoAbi for &mut [T] {
+impl<T: WasmPrimitive + WasmDescribe> WasmIntoAbi for &mut [T] {
     type Abi = WasmSlice;
 
     fn into_abi(self) -> Self::Abi {
-        (&*self).into_abi()
+        lend(self)
+    }
+}
+
+// Borrowed slices and strings are copied into memory by Luau, which is owned and freed by the anchor.
+impl<T: WasmFromAbi> WasmRefFromAbi for [T] {
+    type Abi = WasmSlice;
+    type Anchor = Box<[T]>;
+
+    unsafe fn ref_from_abi(value: Self::Abi) -> Self::Anchor {
+        Box::from_abi(value)
+    }
+}
+
+impl WasmRefFromAbi for str {
+    type Abi = WasmSlice;
+    type Anchor = Box<str>;
+
+    unsafe fn ref_from_abi(value: Self::Abi) -> Self::Anchor {
+        String::from_abi(value).into_boxed_str()
+    }
+}
+
+// Luau copies the elements back into its table once the call has finished, and frees the memory.
+impl<T: WasmPrimitive + WasmDescribe + 'static> WasmRefMutFromAbi for [T] {
+    type Abi = WasmSlice;
+    type Anchor = &'static mut [T];
+
+    unsafe fn ref_mut_from_abi(value: Self::Abi) -> Self::Anchor {
+        std::slice::from_raw_parts_mut(value.ptr as *mut T, value.len)
     }
 }
 
@@ -109,3 +147,46 @@ impl<'a> WasmIntoAbi for &'a str {
         self.as_bytes().into_abi()
     }
 }
+
+/// Lends a vector received from Luau, writing it back into its header once the call has finished.
+///
+/// The vector can be resized, so Luau reads the new pointer and length out of the header afterwards.
+pub struct WasmVecAnchor<T: WasmFromAbi + WasmIntoAbi> {
+    header: *mut WasmSlice,
+    vec: ManuallyDrop<Vec<T>>,
+}
+
+impl<T: WasmFromAbi + WasmIntoAbi> Deref for WasmVecAnchor<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T: WasmFromAbi + WasmIntoAbi> DerefMut for WasmVecAnchor<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec
+    }
+}
+
+impl<T: WasmFromAbi + WasmIntoAbi> Drop for WasmVecAnchor<T> {
+    fn drop(&mut self) {
+        let vec = unsafe { ManuallyDrop::take(&mut self.vec) };
+        unsafe { self.header.write(vec.into_abi()) };
+    }
+}
+
+impl<T: WasmFromAbi + WasmIntoAbi> WasmRefMutFromAbi for Vec<T> {
+    type Abi = u32;
+    type Anchor = WasmVecAnchor<T>;
+
+    unsafe fn ref_mut_from_abi(value: Self::Abi) -> Self::Anchor {
+        let header = value as *mut WasmSlice;
+
+        WasmVecAnchor {
+            header,
+            vec: ManuallyDrop::new(Vec::from_abi(header.read())),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use super::{wasm_abi::WasmAbi, wasm_primitive::WasmPrimitive};
+
+/// The items of a tuple, the primitives are flattened so the layout matches the one the CLI computes.
+///
+/// Tuples are only returned, so this is always written to memory rather than passed as a parameter.
+#[repr(C)]
+pub struct WasmTuple<A: WasmAbi, B: WasmAbi, C: WasmAbi = (), D: WasmAbi = ()> {
+    a1: A::Prim1,
+    a2: A::Prim2,
+    a3: A::Prim3,
+    a4: A::Prim4,
+    b1: B::Prim1,
+    b2: B::Prim2,
+    b3: B::Prim3,
+    b4: B::Prim4,
+    c1: C::Prim1,
+    c2: C::Prim2,
+    c3: C::Prim3,
+    c4: C::Prim4,
+    d1: D::Prim1,
+    d2: D::Prim2,
+    d3: D::Prim3,
+    d4: D::Prim4,
+}
+
+impl<A: WasmAbi, B: WasmAbi, C: WasmAbi, D: WasmAbi> WasmTuple<A, B, C, D> {
+    pub(crate) fn new(a: A, b: B, c: C, d: D) -> Self {
+        let (a1, a2, a3, a4) = a.split();
+        let (b1, b2, b3, b4) = b.split();
+        let (c1, c2, c3, c4) = c.split();
+        let (d1, d2, d3, d4) = d.split();
+
+        Self {
+            a1,
+            a2,
+            a3,
+            a4,
+            b1,
+            b2,
+            b3,
+            b4,
+            c1,
+            c2,
+            c3,
+            c4,
+            d1,
+            d2,
+            d3,
+            d4,
+        }
+    }
+
+    pub(crate) fn join(self) -> (A, B, C, D) {
+        (
+            A::join(self.a1, self.a2, self.a3, self.a4),
+            B::join(self.b1, self.b2, self.b3, self.b4),
+            C::join(self.c1, self.c2, self.c3, self.c4),
+            D::join(self.d1, self.d2, self.d3, self.d4),
+        )
+    }
+}
+
+impl<A: WasmAbi, B: WasmAbi, C: WasmAbi, D: WasmAbi> Clone for WasmTuple<A, B, C, D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: WasmAbi, B: WasmAbi, C: WasmAbi, D: WasmAbi> Copy for WasmTuple<A, B, C, D> {}
+
+impl<A: WasmAbi, B: WasmAbi, C: WasmAbi, D: WasmAbi> Default for WasmTuple<A, B, C, D> {
+    fn default() -> Self {
+        Self {
+            a1: Default::default(),
+            a2: Default::default(),
+            a3: Default::default(),
+            a4: Default::default(),
+            b1: Default::default(),
+            b2: Default::default(),
+            b3: Default::default(),
+            b4: Default::default(),
+            c1: Default::default(),
+            c2: Default::default(),
+            c3: Default::default(),
+            c4: Default::default(),
+            d1: Default::default(),
+            d2: Default::default(),
+            d3: Default::default(),
+            d4: Default::default(),
+        }
+    }
+}
+
+// SAFETY: the fields are primitives which are safe to pass through the ABI, and the struct is only returned.
+unsafe impl<A: WasmAbi, B: WasmAbi, C: WasmAbi, D: WasmAbi> WasmPrimitive
+    for WasmTuple<A, B, C, D>
+{
+}
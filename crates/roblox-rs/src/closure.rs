@@ -0,0 +1,152 @@
+use crate::{
+    internal::{ReturnAbi, WasmAbi, WasmDescribe, WasmFromAbi, WasmIntoAbi},
+    runtime::closure_drop,
+};
+
+/// A Rust closure which can be passed to Luau as a function, e.g `Closure<dyn FnMut(f64) -> bool>`.
+///
+/// The Luau function stays callable until the `Closure` is dropped, after which calling it raises an error.
+pub struct Closure<T: ?Sized> {
+    data: Box<Box<T>>,
+}
+
+impl<T: ?Sized + WasmClosure> Closure<T> {
+    pub fn new<F: IntoWasmClosure<T> + 'static>(f: F) -> Self {
+        Self {
+            data: Box::new(Box::new(f).unsize()),
+        }
+    }
+
+    /// Leaks the closure, so the Luau function remains callable forever.
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+
+    fn data_ptr(&self) -> u32 {
+        &*self.data as *const Box<T> as u32
+    }
+}
+
+impl<T: ?Sized> Drop for Closure<T> {
+    fn drop(&mut self) {
+        closure_drop(&*self.data as *const Box<T> as u32);
+    }
+}
+
+/// Implemented for the `dyn FnMut` types which can be passed to Luau.
+pub trait WasmClosure {
+    /// Describes the arguments and return type of the closure.
+    fn describe();
+
+    /// The function table index of the shim which Luau calls to invoke the closure.
+    fn invoke_index() -> u32;
+}
+
+/// Converts a concrete closure into the boxed `dyn FnMut` type stored by `Closure`.
+pub trait IntoWasmClosure<T: ?Sized> {
+    fn unsize(self: Box<Self>) -> Box<T>;
+}
+
+impl<T: ?Sized + WasmClosure> WasmDescribe for Closure<T> {
+    #[inline(always)]
+    fn describe() {
+        T::describe();
+    }
+}
+
+impl<T: ?Sized + WasmClosure> WasmIntoAbi for &Closure<T> {
+    type Abi = WasmClosureAbi;
+
+    fn into_abi(self) -> Self::Abi {
+        WasmClosureAbi {
+            invoke: T::invoke_index(),
+            data: self.data_ptr(),
+        }
+    }
+}
+
+pub struct WasmClosureAbi {
+    invoke: u32,
+    data: u32,
+}
+
+impl WasmAbi for WasmClosureAbi {
+    type Prim1 = u32;
+    type Prim2 = u32;
+    type Prim3 = ();
+    type Prim4 = ();
+
+    fn join(prim1: Self::Prim1, prim2: Self::Prim2, _: Self::Prim3, _: Self::Prim4) -> Self {
+        Self {
+            invoke: prim1,
+            data: prim2,
+        }
+    }
+
+    fn split(self) -> (Self::Prim1, Self::Prim2, Self::Prim3, Self::Prim4) {
+        (self.invoke, self.data, (), ())
+    }
+}
+
+macro_rules! closures {
+    ($($count:literal => ($($arg:ident $prim1:ident $prim2:ident $prim3:ident $prim4:ident)*);)*) => {
+        $(
+            impl<$($arg,)* R> WasmClosure for dyn FnMut($($arg),*) -> R
+            where
+                $($arg: WasmFromAbi + 'static,)*
+                R: WasmIntoAbi + 'static,
+            {
+                #[inline(always)]
+                fn describe() {
+                    use crate::internal::*;
+
+                    describe(FUNCTION);
+                    describe($count);
+                    $(<$arg as WasmDescribe>::describe();)*
+                    <R as WasmDescribe>::describe();
+                }
+
+                fn invoke_index() -> u32 {
+                    #[allow(clippy::too_many_arguments)]
+                    unsafe extern "C" fn invoke<$($arg: WasmFromAbi,)* R: WasmIntoAbi>(
+                        data: u32,
+                        $(
+                            $prim1: <$arg::Abi as WasmAbi>::Prim1,
+                            $prim2: <$arg::Abi as WasmAbi>::Prim2,
+                            $prim3: <$arg::Abi as WasmAbi>::Prim3,
+                            $prim4: <$arg::Abi as WasmAbi>::Prim4,
+                        )*
+                    ) -> ReturnAbi<R::Abi> {
+                        let f = &mut *(data as *mut Box<dyn FnMut($($arg),*) -> R>);
+                        $(
+                            let $prim1 = $arg::from_abi(<$arg::Abi as WasmAbi>::join($prim1, $prim2, $prim3, $prim4));
+                        )*
+
+                        ReturnAbi::from(f($($prim1),*).into_abi())
+                    }
+
+                    invoke::<$($arg,)* R> as *const () as u32
+                }
+            }
+
+            impl<F, $($arg,)* R> IntoWasmClosure<dyn FnMut($($arg),*) -> R> for F
+            where
+                F: FnMut($($arg),*) -> R + 'static,
+                $($arg: WasmFromAbi + 'static,)*
+                R: WasmIntoAbi + 'static,
+            {
+                fn unsize(self: Box<Self>) -> Box<dyn FnMut($($arg),*) -> R> {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+closures!(
+    0 => ();
+    1 => (A a1 a2 a3 a4);
+    2 => (A a1 a2 a3 a4 B b1 b2 b3 b4);
+    3 => (A a1 a2 a3 a4 B b1 b2 b3 b4 C c1 c2 c3 c4);
+    4 => (A a1 a2 a3 a4 B b1 b2 b3 b4 C c1 c2 c3 c4 D d1 d2 d3 d4);
+);
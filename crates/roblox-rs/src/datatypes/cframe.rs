@@ -0,0 +1,321 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::internal::{describe, WasmDescribe, WasmFromAbi, WasmIntoAbi, WasmRefIntoAbi, CFRAME};
+
+use super::Vector3;
+
+type Matrix = [[f32; 3]; 3];
+
+const IDENTITY_MATRIX: Matrix = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// A Roblox `CFrame`, which is a position and a rotation matrix.
+///
+/// The rotation is stored in rows, in the same order as the components returned by `CFrame:GetComponents()`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CFrame {
+    pub position: Vector3,
+    pub rotation: [[f32; 3]; 3],
+}
+
+impl CFrame {
+    pub const IDENTITY: CFrame = CFrame::from_position(Vector3::ZERO);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self::from_position(Vector3::new(x, y, z))
+    }
+
+    pub const fn from_position(position: Vector3) -> Self {
+        Self {
+            position,
+            rotation: IDENTITY_MATRIX,
+        }
+    }
+
+    /// Creates a rotation which applies the angles in radians in Z, Y, X order, like `CFrame.Angles`.
+    pub fn from_euler_angles_xyz(rx: f32, ry: f32, rz: f32) -> Self {
+        let (sx, cx) = rx.sin_cos();
+        let (sy, cy) = ry.sin_cos();
+        let (sz, cz) = rz.sin_cos();
+
+        let x = [[1.0, 0.0, 0.0], [0.0, cx, -sx], [0.0, sx, cx]];
+        let y = [[cy, 0.0, sy], [0.0, 1.0, 0.0], [-sy, 0.0, cy]];
+        let z = [[cz, -sz, 0.0], [sz, cz, 0.0], [0.0, 0.0, 1.0]];
+
+        Self {
+            position: Vector3::ZERO,
+            rotation: mul_matrix(mul_matrix(x, y), z),
+        }
+    }
+
+    /// Creates a rotation of `angle` radians around the axis.
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        let Vector3 { x, y, z } = axis.unit();
+        let (s, c) = angle.sin_cos();
+        let t = 1.0 - c;
+
+        Self {
+            position: Vector3::ZERO,
+            rotation: [
+                [t * x * x + c, t * x * y - s * z, t * x * z + s * y],
+                [t * x * y + s * z, t * y * y + c, t * y * z - s * x],
+                [t * x * z - s * y, t * y * z + s * x, t * z * z + c],
+            ],
+        }
+    }
+
+    /// Creates a `CFrame` at `at` which faces towards `target`, like `CFrame.lookAt`.
+    pub fn look_at(at: Vector3, target: Vector3, up: Vector3) -> Self {
+        let look = (target - at).unit();
+        let right = look.cross(up).unit();
+        let up = right.cross(look);
+
+        Self::from_columns(at, right, up, -look)
+    }
+
+    fn from_columns(position: Vector3, x: Vector3, y: Vector3, z: Vector3) -> Self {
+        Self {
+            position,
+            rotation: [[x.x, y.x, z.x], [x.y, y.y, z.y], [x.z, y.z, z.z]],
+        }
+    }
+
+    fn column(self, index: usize) -> Vector3 {
+        let [r0, r1, r2] = self.rotation;
+        Vector3::new(r0[index], r1[index], r2[index])
+    }
+
+    pub fn right_vector(self) -> Vector3 {
+        self.column(0)
+    }
+
+    pub fn up_vector(self) -> Vector3 {
+        self.column(1)
+    }
+
+    pub fn look_vector(self) -> Vector3 {
+        -self.column(2)
+    }
+
+    pub fn inverse(self) -> Self {
+        let rotation = transpose(self.rotation);
+
+        Self {
+            position: -rotate(rotation, self.position),
+            rotation,
+        }
+    }
+
+    pub fn point_to_world_space(self, point: Vector3) -> Vector3 {
+        rotate(self.rotation, point) + self.position
+    }
+
+    pub fn point_to_object_space(self, point: Vector3) -> Vector3 {
+        rotate(transpose(self.rotation), point - self.position)
+    }
+
+    pub fn vector_to_world_space(self, vector: Vector3) -> Vector3 {
+        rotate(self.rotation, vector)
+    }
+
+    pub fn vector_to_object_space(self, vector: Vector3) -> Vector3 {
+        rotate(transpose(self.rotation), vector)
+    }
+
+    /// Interpolates the position linearly, and the rotation along the shortest arc.
+    pub fn lerp(self, goal: Self, alpha: f32) -> Self {
+        let from = to_quaternion(self.rotation);
+        let mut to = to_quaternion(goal.rotation);
+
+        let mut dot: f32 = from.iter().zip(to).map(|(a, b)| a * b).sum();
+        if dot < 0.0 {
+            to = to.map(|v| -v);
+            dot = -dot;
+        }
+
+        // Nearly identical rotations are interpolated linearly, since the angle between them is too small to divide by.
+        let (weight_from, weight_to) = if dot > 0.9995 {
+            (1.0 - alpha, alpha)
+        } else {
+            let angle = dot.acos();
+            let sin = angle.sin();
+            (
+                ((1.0 - alpha) * angle).sin() / sin,
+                (alpha * angle).sin() / sin,
+            )
+        };
+
+        let mut quaternion = [0.0; 4];
+        for (index, value) in quaternion.iter_mut().enumerate() {
+            *value = from[index] * weight_from + to[index] * weight_to;
+        }
+
+        Self {
+            position: self.position.lerp(goal.position, alpha),
+            rotation: from_quaternion(quaternion),
+        }
+    }
+}
+
+impl Default for CFrame {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for CFrame {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            position: self * other.position,
+            rotation: mul_matrix(self.rotation, other.rotation),
+        }
+    }
+}
+
+impl Mul<Vector3> for CFrame {
+    type Output = Vector3;
+
+    fn mul(self, point: Vector3) -> Vector3 {
+        self.point_to_world_space(point)
+    }
+}
+
+impl Add<Vector3> for CFrame {
+    type Output = Self;
+
+    fn add(self, offset: Vector3) -> Self {
+        Self {
+            position: self.position + offset,
+            ..self
+        }
+    }
+}
+
+impl Sub<Vector3> for CFrame {
+    type Output = Self;
+
+    fn sub(self, offset: Vector3) -> Self {
+        self + -offset
+    }
+}
+
+fn rotate(matrix: Matrix, vector: Vector3) -> Vector3 {
+    let [r0, r1, r2] = matrix.map(|[x, y, z]| Vector3::new(x, y, z).dot(vector));
+    Vector3::new(r0, r1, r2)
+}
+
+fn transpose(matrix: Matrix) -> Matrix {
+    let mut out = [[0.0; 3]; 3];
+    for (row, values) in matrix.iter().enumerate() {
+        for (column, value) in values.iter().enumerate() {
+            out[column][row] = *value;
+        }
+    }
+    out
+}
+
+fn mul_matrix(a: Matrix, b: Matrix) -> Matrix {
+    let mut out = [[0.0; 3]; 3];
+    for (row, values) in out.iter_mut().enumerate() {
+        for (column, value) in values.iter_mut().enumerate() {
+            *value = (0..3).map(|index| a[row][index] * b[index][column]).sum();
+        }
+    }
+    out
+}
+
+/// Converts a rotation matrix into a quaternion, ordered as `[x, y, z, w]`.
+fn to_quaternion(m: Matrix) -> [f32; 4] {
+    let trace = m[0][0] + m[1][1] + m[2][2];
+
+    if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        [
+            (m[2][1] - m[1][2]) / s,
+            (m[0][2] - m[2][0]) / s,
+            (m[1][0] - m[0][1]) / s,
+            s / 4.0,
+        ]
+    } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+        let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+        [
+            s / 4.0,
+            (m[0][1] + m[1][0]) / s,
+            (m[0][2] + m[2][0]) / s,
+            (m[2][1] - m[1][2]) / s,
+        ]
+    } else if m[1][1] > m[2][2] {
+        let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+        [
+            (m[0][1] + m[1][0]) / s,
+            s / 4.0,
+            (m[1][2] + m[2][1]) / s,
+            (m[0][2] - m[2][0]) / s,
+        ]
+    } else {
+        let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+        [
+            (m[0][2] + m[2][0]) / s,
+            (m[1][2] + m[2][1]) / s,
+            s / 4.0,
+            (m[1][0] - m[0][1]) / s,
+        ]
+    }
+}
+
+fn from_quaternion(quaternion: [f32; 4]) -> Matrix {
+    let length = quaternion.iter().map(|v| v * v).sum::<f32>().sqrt();
+    let [x, y, z, w] = quaternion.map(|v| v / length);
+
+    [
+        [
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y - z * w),
+            2.0 * (x * z + y * w),
+        ],
+        [
+            2.0 * (x * y + z * w),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z - x * w),
+        ],
+        [
+            2.0 * (x * z - y * w),
+            2.0 * (y * z + x * w),
+            1.0 - 2.0 * (x * x + y * y),
+        ],
+    ]
+}
+
+impl WasmDescribe for CFrame {
+    #[inline(always)]
+    fn describe() {
+        describe(CFRAME);
+    }
+}
+
+// A `CFrame` has more components than fit in the ABI, so it's passed as a pointer to a boxed copy.
+impl WasmIntoAbi for CFrame {
+    type Abi = u32;
+
+    fn into_abi(self) -> Self::Abi {
+        Box::into_raw(Box::new(self)) as u32
+    }
+}
+
+impl WasmRefIntoAbi for CFrame {
+    type Abi = u32;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        (*self).into_abi()
+    }
+}
+
+impl WasmFromAbi for CFrame {
+    type Abi = u32;
+
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        *Box::from_raw(value as *mut CFrame)
+    }
+}
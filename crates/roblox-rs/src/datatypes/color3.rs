@@ -0,0 +1,83 @@
+use crate::internal::WasmAbi;
+
+/// A Roblox `Color3`, which is passed by value as its components, each ranging from 0 to 1.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Color3 {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl Color3 {
+    pub const fn new(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b }
+    }
+
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0)
+    }
+
+    /// Creates a color from a hue, saturation and value, each ranging from 0 to 1 like `Color3.fromHSV`.
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let sector = (hue.rem_euclid(1.0) * 6.0).floor();
+        let fraction = hue.rem_euclid(1.0) * 6.0 - sector;
+        let p = value * (1.0 - saturation);
+        let q = value * (1.0 - fraction * saturation);
+        let t = value * (1.0 - (1.0 - fraction) * saturation);
+
+        match sector as u32 {
+            0 => Self::new(value, t, p),
+            1 => Self::new(q, value, p),
+            2 => Self::new(p, value, t),
+            3 => Self::new(p, q, value),
+            4 => Self::new(t, p, value),
+            _ => Self::new(value, p, q),
+        }
+    }
+
+    /// Returns the hue, saturation and value of the color, each ranging from 0 to 1 like `Color3:ToHSV()`.
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == self.r {
+            ((self.g - self.b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            (self.b - self.r) / delta + 2.0
+        } else {
+            (self.r - self.g) / delta + 4.0
+        };
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+        (hue / 6.0, saturation, max)
+    }
+
+    pub fn lerp(self, goal: Self, alpha: f32) -> Self {
+        Self::new(
+            self.r + (goal.r - self.r) * alpha,
+            self.g + (goal.g - self.g) * alpha,
+            self.b + (goal.b - self.b) * alpha,
+        )
+    }
+}
+
+impl WasmAbi for Color3 {
+    type Prim1 = f32;
+    type Prim2 = f32;
+    type Prim3 = f32;
+    type Prim4 = ();
+
+    #[inline(always)]
+    fn split(self) -> (Self::Prim1, Self::Prim2, Self::Prim3, Self::Prim4) {
+        (self.r, self.g, self.b, ())
+    }
+
+    #[inline(always)]
+    fn join(r: f32, g: f32, b: f32, _: ()) -> Self {
+        Self { r, g, b }
+    }
+}
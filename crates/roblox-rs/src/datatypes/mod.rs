@@ -0,0 +1,224 @@
+pub(crate) mod cframe;
+pub(crate) mod color3;
+pub(crate) mod rect;
+pub(crate) mod udim2;
+pub(crate) mod vector2;
+pub(crate) mod vector3;
+
+pub use cframe::CFrame;
+pub use color3::Color3;
+pub use rect::Rect;
+pub use udim2::UDim2;
+pub use vector2::Vector2;
+pub use vector3::Vector3;
+
+/// Implements the ABI of a data type which is passed as its components, rather than as a pointer.
+///
+/// The type must be `#[repr(C)]` and implement `WasmAbi`, so slices of it are lent to Luau as they are.
+macro_rules! component_abi {
+    ($($ty:ident: $tag:ident;)*) => {
+        $(
+            const _: () = {
+                use crate::{
+                    abi::{wasm_ref_into_abi::lend, wasm_slice::WasmSlice},
+                    internal::*,
+                };
+
+                impl WasmDescribe for $ty {
+                    #[inline(always)]
+                    fn describe() {
+                        describe($tag);
+                    }
+                }
+
+                impl WasmIntoAbi for $ty {
+                    type Abi = Self;
+
+                    #[inline(always)]
+                    fn into_abi(self) -> Self::Abi {
+                        self
+                    }
+                }
+
+                impl WasmFromAbi for $ty {
+                    type Abi = Self;
+
+                    #[inline(always)]
+                    unsafe fn from_abi(value: Self::Abi) -> Self {
+                        value
+                    }
+                }
+
+                impl WasmRefIntoAbi for $ty {
+                    type Abi = Self;
+
+                    #[inline(always)]
+                    fn ref_into_abi(&self) -> Self::Abi {
+                        *self
+                    }
+
+                    fn slice_into_abi(slice: &[Self]) -> WasmSlice {
+                        lend(slice)
+                    }
+                }
+
+                // Luau copies the components back into its table once the call has finished, and frees the memory.
+                impl WasmRefMutFromAbi for [$ty] {
+                    type Abi = WasmSlice;
+                    type Anchor = &'static mut [$ty];
+
+                    unsafe fn ref_mut_from_abi(value: Self::Abi) -> Self::Anchor {
+                        std::slice::from_raw_parts_mut(value.ptr as *mut $ty, value.len)
+                    }
+                }
+            };
+        )*
+    };
+}
+
+component_abi!(
+    Vector2: VECTOR2;
+    Vector3: VECTOR3;
+    Color3: COLOR3;
+    UDim2: UDIM2;
+    Rect: RECT;
+);
+
+/// Implements the arithmetic operators of a vector, which are applied to each component.
+macro_rules! vector_ops {
+    ($ty:ident { $($field:ident),* }) => {
+        impl $ty {
+            pub fn dot(self, other: Self) -> f32 {
+                0.0 $(+ self.$field * other.$field)*
+            }
+
+            pub fn magnitude(self) -> f32 {
+                self.dot(self).sqrt()
+            }
+
+            /// Returns the vector scaled to a magnitude of 1, which is `NaN` for a zero vector like in Luau.
+            pub fn unit(self) -> Self {
+                self / self.magnitude()
+            }
+
+            pub fn lerp(self, goal: Self, alpha: f32) -> Self {
+                self + (goal - self) * alpha
+            }
+
+            pub fn abs(self) -> Self {
+                Self { $($field: self.$field.abs()),* }
+            }
+
+            pub fn floor(self) -> Self {
+                Self { $($field: self.$field.floor()),* }
+            }
+
+            pub fn ceil(self) -> Self {
+                Self { $($field: self.$field.ceil()),* }
+            }
+
+            pub fn min(self, other: Self) -> Self {
+                Self { $($field: self.$field.min(other.$field)),* }
+            }
+
+            pub fn max(self, other: Self) -> Self {
+                Self { $($field: self.$field.max(other.$field)),* }
+            }
+
+            /// Whether each component is within `epsilon` of the other vector's component.
+            pub fn fuzzy_eq(self, other: Self, epsilon: f32) -> bool {
+                true $(&& (self.$field - other.$field).abs() <= epsilon)*
+            }
+        }
+
+        impl std::ops::Add for $ty {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl std::ops::Sub for $ty {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl std::ops::Mul for $ty {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Self { $($field: self.$field * other.$field),* }
+            }
+        }
+
+        impl std::ops::Mul<f32> for $ty {
+            type Output = Self;
+
+            fn mul(self, scalar: f32) -> Self {
+                Self { $($field: self.$field * scalar),* }
+            }
+        }
+
+        impl std::ops::Mul<$ty> for f32 {
+            type Output = $ty;
+
+            fn mul(self, vector: $ty) -> $ty {
+                vector * self
+            }
+        }
+
+        impl std::ops::Div for $ty {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self {
+                Self { $($field: self.$field / other.$field),* }
+            }
+        }
+
+        impl std::ops::Div<f32> for $ty {
+            type Output = Self;
+
+            fn div(self, scalar: f32) -> Self {
+                Self { $($field: self.$field / scalar),* }
+            }
+        }
+
+        impl std::ops::Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl std::ops::AddAssign for $ty {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl std::ops::SubAssign for $ty {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl std::ops::MulAssign<f32> for $ty {
+            fn mul_assign(&mut self, scalar: f32) {
+                *self = *self * scalar;
+            }
+        }
+
+        impl std::ops::DivAssign<f32> for $ty {
+            fn div_assign(&mut self, scalar: f32) {
+                *self = *self / scalar;
+            }
+        }
+    };
+}
+
+use vector_ops;
@@ -0,0 +1,46 @@
+use crate::internal::WasmAbi;
+
+use super::Vector2;
+
+/// A Roblox `Rect`, which is passed by value as the components of its corners.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rect {
+    pub min: Vector2,
+    pub max: Vector2,
+}
+
+impl Rect {
+    pub const fn new(min: Vector2, max: Vector2) -> Self {
+        Self { min, max }
+    }
+
+    pub fn width(self) -> f32 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(self) -> f32 {
+        self.max.y - self.min.y
+    }
+
+    pub fn contains(self, point: Vector2) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+impl WasmAbi for Rect {
+    type Prim1 = f32;
+    type Prim2 = f32;
+    type Prim3 = f32;
+    type Prim4 = f32;
+
+    #[inline(always)]
+    fn split(self) -> (Self::Prim1, Self::Prim2, Self::Prim3, Self::Prim4) {
+        (self.min.x, self.min.y, self.max.x, self.max.y)
+    }
+
+    #[inline(always)]
+    fn join(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Self {
+        Self::new(Vector2::new(min_x, min_y), Vector2::new(max_x, max_y))
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::{Add, Neg, Sub};
+
+use crate::internal::WasmAbi;
+
+/// A Roblox `UDim2`, which is passed by value as its scale and offset components.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UDim2 {
+    pub x_scale: f32,
+    pub x_offset: i32,
+    pub y_scale: f32,
+    pub y_offset: i32,
+}
+
+impl UDim2 {
+    pub const fn new(x_scale: f32, x_offset: i32, y_scale: f32, y_offset: i32) -> Self {
+        Self {
+            x_scale,
+            x_offset,
+            y_scale,
+            y_offset,
+        }
+    }
+
+    pub const fn from_scale(x_scale: f32, y_scale: f32) -> Self {
+        Self::new(x_scale, 0, y_scale, 0)
+    }
+
+    pub const fn from_offset(x_offset: i32, y_offset: i32) -> Self {
+        Self::new(0.0, x_offset, 0.0, y_offset)
+    }
+
+    /// Interpolates towards the goal, the offsets are rounded to the nearest pixel.
+    pub fn lerp(self, goal: Self, alpha: f32) -> Self {
+        let offset = |from: i32, to: i32| from + ((to - from) as f32 * alpha).round() as i32;
+
+        Self {
+            x_scale: self.x_scale + (goal.x_scale - self.x_scale) * alpha,
+            x_offset: offset(self.x_offset, goal.x_offset),
+            y_scale: self.y_scale + (goal.y_scale - self.y_scale) * alpha,
+            y_offset: offset(self.y_offset, goal.y_offset),
+        }
+    }
+}
+
+impl Add for UDim2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.x_scale + other.x_scale,
+            self.x_offset + other.x_offset,
+            self.y_scale + other.y_scale,
+            self.y_offset + other.y_offset,
+        )
+    }
+}
+
+impl Sub for UDim2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Neg for UDim2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x_scale, -self.x_offset, -self.y_scale, -self.y_offset)
+    }
+}
+
+impl WasmAbi for UDim2 {
+    type Prim1 = f32;
+    type Prim2 = i32;
+    type Prim3 = f32;
+    type Prim4 = i32;
+
+    #[inline(always)]
+    fn split(self) -> (Self::Prim1, Self::Prim2, Self::Prim3, Self::Prim4) {
+        (self.x_scale, self.x_offset, self.y_scale, self.y_offset)
+    }
+
+    #[inline(always)]
+    fn join(x_scale: f32, x_offset: i32, y_scale: f32, y_offset: i32) -> Self {
+        Self::new(x_scale, x_offset, y_scale, y_offset)
+    }
+}
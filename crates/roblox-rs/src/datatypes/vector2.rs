@@ -0,0 +1,46 @@
+use crate::internal::WasmAbi;
+
+use super::vector_ops;
+
+/// A Roblox `Vector2`, which is passed by value as its components instead of being kept in Luau.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Vector2 {
+    pub const ZERO: Vector2 = Vector2::new(0.0, 0.0);
+    pub const ONE: Vector2 = Vector2::new(1.0, 1.0);
+    pub const X_AXIS: Vector2 = Vector2::new(1.0, 0.0);
+    pub const Y_AXIS: Vector2 = Vector2::new(0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the z component of the cross product, as if the vectors were extended into 3D.
+    pub fn cross(self, other: Self) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+vector_ops!(Vector2 { x, y });
+
+impl WasmAbi for Vector2 {
+    type Prim1 = f32;
+    type Prim2 = f32;
+    type Prim3 = ();
+    type Prim4 = ();
+
+    #[inline(always)]
+    fn split(self) -> (Self::Prim1, Self::Prim2, Self::Prim3, Self::Prim4) {
+        (self.x, self.y, (), ())
+    }
+
+    #[inline(always)]
+    fn join(x: f32, y: f32, _: (), _: ()) -> Self {
+        Self { x, y }
+    }
+}
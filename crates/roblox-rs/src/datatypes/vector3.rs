@@ -0,0 +1,56 @@
+use crate::internal::WasmAbi;
+
+use super::vector_ops;
+
+/// A Roblox `Vector3`, which is passed by value as its components instead of being kept in Luau.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vector3 {
+    pub const ZERO: Vector3 = Vector3::new(0.0, 0.0, 0.0);
+    pub const ONE: Vector3 = Vector3::new(1.0, 1.0, 1.0);
+    pub const X_AXIS: Vector3 = Vector3::new(1.0, 0.0, 0.0);
+    pub const Y_AXIS: Vector3 = Vector3::new(0.0, 1.0, 0.0);
+    pub const Z_AXIS: Vector3 = Vector3::new(0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Returns the angle between the vectors in radians.
+    pub fn angle(self, other: Self) -> f32 {
+        self.cross(other).magnitude().atan2(self.dot(other))
+    }
+}
+
+vector_ops!(Vector3 { x, y, z });
+
+impl WasmAbi for Vector3 {
+    type Prim1 = f32;
+    type Prim2 = f32;
+    type Prim3 = f32;
+    type Prim4 = ();
+
+    #[inline(always)]
+    fn split(self) -> (Self::Prim1, Self::Prim2, Self::Prim3, Self::Prim4) {
+        (self.x, self.y, self.z, ())
+    }
+
+    #[inline(always)]
+    fn join(x: f32, y: f32, z: f32, _: ()) -> Self {
+        Self { x, y, z }
+    }
+}
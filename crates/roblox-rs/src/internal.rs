@@ -2,5 +2,8 @@ pub use crate::abi::wasm_abi::*;
 pub use crate::abi::wasm_describe::*;
 pub use crate::abi::wasm_from_abi::*;
 pub use crate::abi::wasm_into_abi::*;
+pub use crate::abi::wasm_ref_from_abi::*;
+pub use crate::abi::wasm_ref_into_abi::*;
 pub use crate::abi::wasm_return_abi::*;
+pub use crate::closure::{IntoWasmClosure, WasmClosure, WasmClosureAbi};
 pub use crate::ffi::*;
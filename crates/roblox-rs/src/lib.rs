@@ -1,6 +1,18 @@
+// Allows the #[luau] macro to be used inside of this crate.
+extern crate self as roblox_rs;
+
 pub(crate) mod abi;
+pub(crate) mod closure;
+pub(crate) mod datatypes;
 pub(crate) mod ffi;
+pub(crate) mod luau_buffer;
+pub(crate) mod luau_bytes;
+pub(crate) mod luau_error;
+pub(crate) mod luau_function;
 pub(crate) mod luau_value;
+pub(crate) mod panic_hook;
+pub(crate) mod runtime;
+pub(crate) mod variadic;
 
 #[doc(hidden)]
 pub mod internal;
@@ -0,0 +1,83 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+    abi::wasm_slice::WasmSlice,
+    internal::{describe, WasmDescribe, WasmFromAbi, WasmIntoAbi, WasmRefIntoAbi, BUFFER},
+};
+
+/// Bytes which are passed to Luau as a `buffer`, copying the whole buffer at once instead of building a table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LuauBuffer(Vec<u8>);
+
+impl LuauBuffer {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for LuauBuffer {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&[u8]> for LuauBuffer {
+    fn from(value: &[u8]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl From<LuauBuffer> for Vec<u8> {
+    fn from(value: LuauBuffer) -> Self {
+        value.0
+    }
+}
+
+impl Deref for LuauBuffer {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for LuauBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl WasmDescribe for LuauBuffer {
+    #[inline(always)]
+    fn describe() {
+        describe(BUFFER);
+    }
+}
+
+impl WasmIntoAbi for LuauBuffer {
+    type Abi = <Vec<u8> as WasmIntoAbi>::Abi;
+
+    fn into_abi(self) -> Self::Abi {
+        self.0.into_abi()
+    }
+}
+
+impl WasmRefIntoAbi for LuauBuffer {
+    type Abi = WasmSlice;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        self.0.ref_into_abi()
+    }
+}
+
+impl WasmFromAbi for LuauBuffer {
+    type Abi = <Vec<u8> as WasmFromAbi>::Abi;
+
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        Self(Vec::from_abi(value))
+    }
+}
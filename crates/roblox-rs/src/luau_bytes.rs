@@ -0,0 +1,110 @@
+use std::{
+    ops::{Deref, DerefMut},
+    string::FromUtf8Error,
+};
+
+use crate::{
+    abi::wasm_slice::WasmSlice,
+    internal::{describe, WasmDescribe, WasmFromAbi, WasmIntoAbi, WasmRefIntoAbi, BYTES},
+};
+
+/// A Luau string which isn't checked to be valid UTF-8, for strings which hold binary data.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct LuauBytes(Vec<u8>);
+
+impl LuauBytes {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+
+    pub fn into_string(self) -> Result<String, FromUtf8Error> {
+        String::from_utf8(self.0)
+    }
+
+    /// Converts the bytes into a `String`, replacing any invalid UTF-8 with `U+FFFD`.
+    pub fn into_string_lossy(self) -> String {
+        match String::from_utf8(self.0) {
+            Ok(string) => string,
+            Err(error) => String::from_utf8_lossy(error.as_bytes()).into_owned(),
+        }
+    }
+}
+
+impl From<Vec<u8>> for LuauBytes {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&[u8]> for LuauBytes {
+    fn from(value: &[u8]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl From<String> for LuauBytes {
+    fn from(value: String) -> Self {
+        Self(value.into_bytes())
+    }
+}
+
+impl From<&str> for LuauBytes {
+    fn from(value: &str) -> Self {
+        Self(value.as_bytes().to_vec())
+    }
+}
+
+impl From<LuauBytes> for Vec<u8> {
+    fn from(value: LuauBytes) -> Self {
+        value.0
+    }
+}
+
+impl Deref for LuauBytes {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for LuauBytes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl WasmDescribe for LuauBytes {
+    #[inline(always)]
+    fn describe() {
+        describe(BYTES);
+    }
+}
+
+impl WasmIntoAbi for LuauBytes {
+    type Abi = <Vec<u8> as WasmIntoAbi>::Abi;
+
+    fn into_abi(self) -> Self::Abi {
+        self.0.into_abi()
+    }
+}
+
+impl WasmRefIntoAbi for LuauBytes {
+    type Abi = WasmSlice;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        self.0.ref_into_abi()
+    }
+}
+
+impl WasmFromAbi for LuauBytes {
+    type Abi = <Vec<u8> as WasmFromAbi>::Abi;
+
+    unsafe fn from_abi(value: Self::Abi) -> Self {
+        Self(Vec::from_abi(value))
+    }
+}
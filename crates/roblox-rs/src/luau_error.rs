@@ -0,0 +1,25 @@
+use std::fmt::{self, Display};
+
+/// An error raised by Luau, e.g when an import declared as returning `Result<T, LuauError>` fails.
+#[derive(Debug, Clone)]
+pub struct LuauError {
+    message: String,
+}
+
+impl LuauError {
+    pub(crate) fn new(message: String) -> Self {
+        Self { message }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for LuauError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for LuauError {}
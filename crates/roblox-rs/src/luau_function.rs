@@ -0,0 +1,48 @@
+use roblox_rs_macro_definitions::luau;
+
+use crate::{
+    luau_value::{FromLuauValue, IntoLuauValue, LuauValue},
+    runtime,
+};
+
+#[luau]
+extern "C" {
+    /// A Luau function which can be called from Rust, e.g `callback.call::<(f64, &str), bool>((1.0, "a"))`.
+    pub type LuauFunction;
+}
+
+impl LuauFunction {
+    /// Calls the Luau function, returning its first result.
+    pub fn call<A: IntoLuauArgs, R: FromLuauValue>(&self, args: A) -> R {
+        R::from_luau_value(runtime::call(self.as_ref(), args.into_luau_args().into()))
+    }
+}
+
+/// Tuples of values which can be passed as arguments to a Luau function.
+pub trait IntoLuauArgs {
+    fn into_luau_args(self) -> Vec<LuauValue>;
+}
+
+macro_rules! luau_args {
+    ($(($($arg:ident)*);)*) => {
+        $(
+            impl<$($arg: IntoLuauValue),*> IntoLuauArgs for ($($arg,)*) {
+                #[allow(non_snake_case)]
+                fn into_luau_args(self) -> Vec<LuauValue> {
+                    let ($($arg,)*) = self;
+                    vec![$($arg.into_luau_value()),*]
+                }
+            }
+        )*
+    };
+}
+
+luau_args!(
+    ();
+    (A);
+    (A B);
+    (A B C);
+    (A B C D);
+    (A B C D E);
+    (A B C D E F);
+);
@@ -1,7 +1,64 @@
-use crate::internal::{WasmDescribe, WasmFromAbi, WasmIntoAbi};
+use std::mem::ManuallyDrop;
 
+use crate::{
+    internal::{WasmDescribe, WasmFromAbi, WasmIntoAbi, WasmRefFromAbi, WasmRefIntoAbi},
+    luau_error::LuauError,
+    luau_function::IntoLuauArgs,
+    runtime,
+};
+
+/// A value owned by Luau, which is kept alive in the runtime's `HEAP` until the `LuauValue` is dropped.
 pub struct LuauValue(u32);
 
+impl LuauValue {
+    /// Returns the type of the value using Luau's `typeof`, e.g `"number"` or `"Instance"`.
+    pub fn type_of(&self) -> String {
+        runtime::type_of(self)
+    }
+
+    pub fn is_nil(&self) -> bool {
+        runtime::is_nil(self)
+    }
+
+    /// Indexes the value, e.g `value[key]`.
+    pub fn get(&self, key: impl IntoLuauValue) -> LuauValue {
+        runtime::index(self, key.into_luau_value())
+    }
+
+    /// Assigns to an index of the value, e.g `value[key] = new_value`.
+    pub fn set(&self, key: impl IntoLuauValue, value: impl IntoLuauValue) {
+        runtime::new_index(self, key.into_luau_value(), value.into_luau_value());
+    }
+
+    /// Calls the value as a function, returning its first result.
+    pub fn call(&self, args: impl IntoLuauArgs) -> LuauValue {
+        runtime::call(self, args.into_luau_args().into())
+    }
+
+    /// Calls a method on the value using Luau's colon syntax, e.g `value:name(...)`, returning its first result.
+    pub fn call_method(&self, name: &str, args: impl IntoLuauArgs) -> LuauValue {
+        runtime::call_method(self, name, args.into_luau_args().into())
+    }
+
+    /// The error for a conversion which failed, the type is only fetched once the conversion has failed.
+    fn type_error(&self, expected: &str) -> LuauError {
+        LuauError::new(format!("expected {expected}, got {}", self.type_of()))
+    }
+}
+
+impl Clone for LuauValue {
+    /// Stores the same Luau value in a new `HEAP` slot.
+    fn clone(&self) -> Self {
+        runtime::value_clone(self)
+    }
+}
+
+impl Drop for LuauValue {
+    fn drop(&mut self) {
+        runtime::heap_drop(self.0);
+    }
+}
+
 impl WasmDescribe for LuauValue {
     #[inline(always)]
     fn describe() {
@@ -14,8 +71,9 @@ impl WasmDescribe for LuauValue {
 impl WasmIntoAbi for LuauValue {
     type Abi = u32;
 
+    // Ownership of the `HEAP` slot is passed to Luau, which removes it once the value has been read.
     fn into_abi(self) -> Self::Abi {
-        self.0
+        ManuallyDrop::new(self).0
     }
 }
 
@@ -27,6 +85,14 @@ impl WasmIntoAbi for &LuauValue {
     }
 }
 
+impl WasmRefIntoAbi for LuauValue {
+    type Abi = u32;
+
+    fn ref_into_abi(&self) -> Self::Abi {
+        self.0
+    }
+}
+
 impl WasmFromAbi for LuauValue {
     type Abi = u32;
 
@@ -34,3 +100,131 @@ impl WasmFromAbi for LuauValue {
         LuauValue(value)
     }
 }
+
+// Borrowed values are stored in a temporary `HEAP` entry, which Luau removes once the call has finished.
+impl WasmRefFromAbi for LuauValue {
+    type Abi = u32;
+    type Anchor = ManuallyDrop<LuauValue>;
+
+    unsafe fn ref_from_abi(value: Self::Abi) -> Self::Anchor {
+        ManuallyDrop::new(LuauValue(value))
+    }
+}
+
+/// Values which can be converted into a `LuauValue`.
+pub trait IntoLuauValue {
+    fn into_luau_value(self) -> LuauValue;
+}
+
+/// Values which can be created from a `LuauValue`, raising a Luau error if the value has the wrong type.
+pub trait FromLuauValue: Sized {
+    fn from_luau_value(value: LuauValue) -> Self;
+}
+
+impl IntoLuauValue for LuauValue {
+    fn into_luau_value(self) -> LuauValue {
+        self
+    }
+}
+
+impl FromLuauValue for LuauValue {
+    fn from_luau_value(value: LuauValue) -> Self {
+        value
+    }
+}
+
+impl FromLuauValue for () {
+    fn from_luau_value(_: LuauValue) -> Self {}
+}
+
+impl IntoLuauValue for &str {
+    fn into_luau_value(self) -> LuauValue {
+        runtime::str_into_value(self)
+    }
+}
+
+macro_rules! luau_value_conversions {
+    ($($ty:ty: $into:ident, $from:ident;)*) => {
+        $(
+            impl IntoLuauValue for $ty {
+                fn into_luau_value(self) -> LuauValue {
+                    runtime::$into(self)
+                }
+            }
+
+            impl FromLuauValue for $ty {
+                fn from_luau_value(value: LuauValue) -> Self {
+                    runtime::$from(value)
+                }
+            }
+        )*
+    };
+}
+
+luau_value_conversions!(
+    f32: f32_into_value, value_into_f32;
+    f64: f64_into_value, value_into_f64;
+    u64: u64_into_value, value_into_u64;
+    i64: i64_into_value, value_into_i64;
+    char: char_into_value, value_into_char;
+    bool: bool_into_value, value_into_bool;
+    String: string_into_value, value_into_string;
+);
+
+// Integers are checked in Luau, so fractions and out of range numbers raise an error instead of being truncated.
+macro_rules! luau_integer_conversions {
+    ($($ty:ty: $into:ident, $from:ident;)*) => {
+        $(
+            impl IntoLuauValue for $ty {
+                fn into_luau_value(self) -> LuauValue {
+                    runtime::$into(self)
+                }
+            }
+
+            impl FromLuauValue for $ty {
+                fn from_luau_value(value: LuauValue) -> Self {
+                    runtime::$from(value, <$ty>::MIN as f64, <$ty>::MAX as f64)
+                }
+            }
+        )*
+    };
+}
+
+luau_integer_conversions!(
+    u8: u8_into_value, value_into_u8;
+    u16: u16_into_value, value_into_u16;
+    u32: u32_into_value, value_into_u32;
+    i8: i8_into_value, value_into_i8;
+    i16: i16_into_value, value_into_i16;
+    i32: i32_into_value, value_into_i32;
+    usize: usize_into_value, value_into_usize;
+    isize: isize_into_value, value_into_isize;
+);
+
+impl TryFrom<LuauValue> for f64 {
+    type Error = LuauError;
+
+    fn try_from(value: LuauValue) -> Result<Self, Self::Error> {
+        runtime::value_try_f64(&value).ok_or_else(|| value.type_error("number"))
+    }
+}
+
+impl TryFrom<LuauValue> for bool {
+    type Error = LuauError;
+
+    fn try_from(value: LuauValue) -> Result<Self, Self::Error> {
+        runtime::value_try_bool(&value).ok_or_else(|| value.type_error("boolean"))
+    }
+}
+
+impl TryFrom<LuauValue> for String {
+    type Error = LuauError;
+
+    // The string is received as bytes, so invalid UTF-8 is returned as an error instead of raised by the runtime.
+    fn try_from(value: LuauValue) -> Result<Self, Self::Error> {
+        runtime::value_try_bytes(&value)
+            .ok_or_else(|| value.type_error("string"))?
+            .into_string()
+            .map_err(|_| LuauError::new("expected a valid UTF-8 string".to_string()))
+    }
+}
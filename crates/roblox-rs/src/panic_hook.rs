@@ -0,0 +1,27 @@
+use std::panic::{self, PanicHookInfo};
+
+use roblox_rs_macro_definitions::intrinsic;
+
+use crate::runtime;
+
+/// Installs a panic hook which raises the panic as a Luau error, this is called by the runtime before anything else.
+#[intrinsic]
+fn set_panic_hook() {
+    panic::set_hook(Box::new(|info| runtime::panic(&format_panic(info))));
+}
+
+fn format_panic(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    let message = match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.as_str(),
+            None => "Box<dyn Any>",
+        },
+    };
+
+    match info.location() {
+        Some(location) => format!("{}:{}: {message}", location.file(), location.line()),
+        None => message.to_string(),
+    }
+}
@@ -1,2 +1,9 @@
-pub use crate::luau_value::LuauValue;
-pub use roblox_rs_macro_definitions::luau;
+pub use crate::closure::Closure;
+pub use crate::datatypes::{CFrame, Color3, Rect, UDim2, Vector2, Vector3};
+pub use crate::luau_buffer::LuauBuffer;
+pub use crate::luau_bytes::LuauBytes;
+pub use crate::luau_error::LuauError;
+pub use crate::luau_function::{IntoLuauArgs, LuauFunction};
+pub use crate::luau_value::{FromLuauValue, IntoLuauValue, LuauValue};
+pub use crate::variadic::Variadic;
+pub use roblox_rs_macro_definitions::{luau, LuauTable};
@@ -0,0 +1,115 @@
+use roblox_rs_macro_definitions::luau;
+
+use crate::{luau_bytes::LuauBytes, luau_value::LuauValue, variadic::Variadic};
+
+// These functions are defined in the header of the generated runtime.
+#[luau]
+extern "C" {
+    #[luau(name = "WASM_CLOSURE_DROP")]
+    pub(crate) fn closure_drop(data: u32);
+
+    #[luau(name = "WASM_PANIC")]
+    pub(crate) fn panic(message: &str);
+
+    #[luau(name = "WASM_HEAP_DROP")]
+    pub(crate) fn heap_drop(id: u32);
+
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn value_clone(value: &LuauValue) -> LuauValue;
+
+    #[luau(name = "WASM_CALL")]
+    pub(crate) fn call(function: &LuauValue, args: Variadic<LuauValue>) -> LuauValue;
+
+    #[luau(name = "WASM_CALL_METHOD")]
+    pub(crate) fn call_method(
+        object: &LuauValue,
+        name: &str,
+        args: Variadic<LuauValue>,
+    ) -> LuauValue;
+
+    #[luau(name = "WASM_INDEX")]
+    pub(crate) fn index(object: &LuauValue, key: LuauValue) -> LuauValue;
+
+    #[luau(name = "WASM_NEW_INDEX")]
+    pub(crate) fn new_index(object: &LuauValue, key: LuauValue, value: LuauValue);
+
+    #[luau(name = "WASM_IS_NIL")]
+    pub(crate) fn is_nil(value: &LuauValue) -> bool;
+
+    #[luau(name = "WASM_TYPEOF")]
+    pub(crate) fn type_of(value: &LuauValue) -> String;
+
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn u8_into_value(value: u8) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn u16_into_value(value: u16) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn u32_into_value(value: u32) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn i8_into_value(value: i8) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn i16_into_value(value: i16) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn i32_into_value(value: i32) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn f32_into_value(value: f32) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn f64_into_value(value: f64) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn u64_into_value(value: u64) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn i64_into_value(value: i64) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn usize_into_value(value: usize) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn isize_into_value(value: isize) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn char_into_value(value: char) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn bool_into_value(value: bool) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn str_into_value(value: &str) -> LuauValue;
+    #[luau(name = "WASM_IDENTITY")]
+    pub(crate) fn string_into_value(value: String) -> LuauValue;
+
+    #[luau(name = "WASM_TO_INTEGER")]
+    pub(crate) fn value_into_u8(value: LuauValue, min: f64, max: f64) -> u8;
+    #[luau(name = "WASM_TO_INTEGER")]
+    pub(crate) fn value_into_u16(value: LuauValue, min: f64, max: f64) -> u16;
+    #[luau(name = "WASM_TO_INTEGER")]
+    pub(crate) fn value_into_u32(value: LuauValue, min: f64, max: f64) -> u32;
+    #[luau(name = "WASM_TO_INTEGER")]
+    pub(crate) fn value_into_i8(value: LuauValue, min: f64, max: f64) -> i8;
+    #[luau(name = "WASM_TO_INTEGER")]
+    pub(crate) fn value_into_i16(value: LuauValue, min: f64, max: f64) -> i16;
+    #[luau(name = "WASM_TO_INTEGER")]
+    pub(crate) fn value_into_i32(value: LuauValue, min: f64, max: f64) -> i32;
+    #[luau(name = "WASM_TO_NUMBER")]
+    pub(crate) fn value_into_f32(value: LuauValue) -> f32;
+    #[luau(name = "WASM_TO_NUMBER")]
+    pub(crate) fn value_into_f64(value: LuauValue) -> f64;
+    #[luau(name = "WASM_TO_NUMBER")]
+    pub(crate) fn value_into_u64(value: LuauValue) -> u64;
+    #[luau(name = "WASM_TO_NUMBER")]
+    pub(crate) fn value_into_i64(value: LuauValue) -> i64;
+    #[luau(name = "WASM_TO_INTEGER")]
+    pub(crate) fn value_into_usize(value: LuauValue, min: f64, max: f64) -> usize;
+    #[luau(name = "WASM_TO_INTEGER")]
+    pub(crate) fn value_into_isize(value: LuauValue, min: f64, max: f64) -> isize;
+    #[luau(name = "WASM_TO_STRING")]
+    pub(crate) fn value_into_char(value: LuauValue) -> char;
+    #[luau(name = "WASM_TO_BOOLEAN")]
+    pub(crate) fn value_into_bool(value: LuauValue) -> bool;
+    #[luau(name = "WASM_TO_STRING")]
+    pub(crate) fn value_into_string(value: LuauValue) -> String;
+    #[luau(name = "WASM_TO_STRING")]
+    pub(crate) fn value_into_bytes(value: LuauValue) -> LuauBytes;
+
+    // These return nil instead of raising an error, so `TryFrom` only crosses into Luau once.
+    #[luau(name = "WASM_TRY_NUMBER")]
+    pub(crate) fn value_try_f64(value: &LuauValue) -> Option<f64>;
+    #[luau(name = "WASM_TRY_BOOLEAN")]
+    pub(crate) fn value_try_bool(value: &LuauValue) -> Option<bool>;
+    #[luau(name = "WASM_TRY_STRING")]
+    pub(crate) fn value_try_bytes(value: &LuauValue) -> Option<LuauBytes>;
+}
@@ -0,0 +1,40 @@
+use crate::internal::{describe, WasmDescribe, WasmIntoAbi, VARIADIC};
+
+/// Values which are unpacked into the arguments of a variadic Luau function, e.g `print(...)`.
+///
+/// This must be the last parameter of an import, since Luau only expands the values in the last position.
+pub struct Variadic<T>(pub Vec<T>);
+
+impl<T> From<Vec<T>> for Variadic<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Variadic<T> {
+    fn from(value: [T; N]) -> Self {
+        Self(value.into())
+    }
+}
+
+impl<T> FromIterator<T> for Variadic<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: WasmDescribe> WasmDescribe for Variadic<T> {
+    #[inline(always)]
+    fn describe() {
+        describe(VARIADIC);
+        T::describe();
+    }
+}
+
+impl<T: WasmIntoAbi> WasmIntoAbi for Variadic<T> {
+    type Abi = <Vec<T> as WasmIntoAbi>::Abi;
+
+    fn into_abi(self) -> Self::Abi {
+        self.0.into_abi()
+    }
+}
@@ -17,6 +17,8 @@ pub fn decode(content: &[u8]) -> SharedContext {
     while let Ok(value) = bincode::deserialize_from::<_, SharedContext>(&mut cursor) {
         context.imports.extend(value.imports);
         context.exports.extend(value.exports);
+        context.classes.extend(value.classes);
+        context.enums.extend(value.enums);
         context.main_fns.extend(value.main_fns);
         context.intrinsics.extend(value.intrinsics);
     }
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 pub struct SharedContext {
     pub imports: Vec<SharedImportFunction>,
     pub exports: Vec<SharedExportFunction>,
+    pub classes: Vec<SharedClass>,
+    pub enums: Vec<SharedEnum>,
     pub main_fns: Vec<String>,
     pub intrinsics: Vec<SharedIntrinsic>,
 }
@@ -14,6 +16,21 @@ pub struct SharedImportFunction {
     pub luau_name: String,
     pub describe_name: String,
     pub export_name: String,
+    pub kind: SharedImportKind,
+    /// The Luau path the import is resolved against, e.g `game.Workspace`.
+    pub namespace: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedImportKind {
+    /// Calls the Luau function directly, e.g `luau_name(...)`.
+    Function,
+    /// Calls a method on the namespace or first argument, e.g `arg:luau_name(...)`.
+    Method,
+    /// Reads a property from the namespace or first argument, e.g `arg.luau_name`.
+    Getter,
+    /// Assigns the last argument to a property of the namespace or first argument, e.g `arg.luau_name = value`.
+    Setter,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -22,6 +39,38 @@ pub struct SharedExportFunction {
     pub luau_name: String,
     pub export_name: String,
     pub describe_name: String,
+    /// The Rust name of the class this function is defined on, if any.
+    pub class: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SharedClass {
+    pub rust_name: String,
+    pub luau_name: String,
+    pub drop_name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SharedEnum {
+    pub rust_name: String,
+    pub luau_name: String,
+    pub kind: SharedEnumKind,
+    /// The variants in declaration order, Rust sends the index of the variant over the ABI.
+    pub variants: Vec<SharedEnumVariant>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedEnumKind {
+    /// Variants are represented by their name in Luau, e.g `"Red"`.
+    String,
+    /// Variants are represented by their discriminant in Luau, e.g `0`.
+    Number,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SharedEnumVariant {
+    pub luau_name: String,
+    pub discriminant: i64,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
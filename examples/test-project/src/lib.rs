@@ -1,17 +1,78 @@
+use std::collections::HashMap;
+
 use roblox_rs::prelude::*;
 
 /// The #[luau] macro is responsible for generating bindings between Rust and Luau.
 /// You can import Luau/Roblox globals using `extern` and simply defining the function signature.
 #[luau]
 extern "C" {
-    // This imports the Luau `print` function and allows you to pass an optional LuauValue to it.
+    // This imports the Luau `print` function and allows you to pass any number of LuauValues to it.
+    // `Variadic` arguments are unpacked into the call, so they must be the last argument.
     // You can change the signature to any values supported by the underlying Luau function.
-    fn print(value: Option<LuauValue>);
+    fn print(values: Variadic<LuauValue>);
 
     // You can import scoped functions, or rename functions, using the `name` attribute.
     // This will call `Vector3.new` in the generated bindings, and return the resulting Vector3 as a LuauValue.
+    // Roblox data types like `Vector3` can also be passed by value as Rust structs, see `step_projectile` below.
     #[luau(name = "Vector3.new")]
     fn vector3_new(x: f64, y: f64, z: f64) -> LuauValue;
+
+    // Luau types can be declared using `type`, which creates a distinct Rust type for values of that type.
+    // This lets the Rust compiler check that a `Part` isn't passed where a different type is expected.
+    type Part;
+
+    #[luau(name = "Instance.new")]
+    fn part_new(class_name: &str) -> Part;
+
+    // Methods are called on the first argument using the `method` attribute.
+    // This will call `this:Destroy()` in the generated bindings.
+    #[luau(method = "Destroy")]
+    fn destroy(this: &Part);
+
+    // Properties are read and assigned using the `get` and `set` attributes.
+    // These will read `this.Name` and assign `this.Name = value` in the generated bindings.
+    #[luau(get = "Name")]
+    fn part_name(this: &Part) -> String;
+
+    #[luau(set = "Name")]
+    fn set_part_name(this: &Part, value: &str);
+
+    // Closures are passed to Luau by reference, and become regular Luau functions.
+    // The Luau function can be called until the closure is dropped.
+    #[luau(name = "task.delay")]
+    fn task_delay(seconds: f64, callback: &Closure<dyn FnMut()>);
+
+    // Imports returning `Result<T, LuauError>` are called using `pcall`, so Luau errors are returned instead of raised.
+    #[luau(name = "error")]
+    fn raise_error(message: &str) -> Result<(), LuauError>;
+
+    // Mutable slices are written back once the Luau function returns, so Rust sees the sorted values.
+    #[luau(name = "table.sort")]
+    fn table_sort(values: &mut [f64]);
+
+    // Borrowed slices of any element type are passed without being cloned into a `Vec`.
+    #[luau(name = "table.concat")]
+    fn table_concat(values: &[String], separator: &str) -> String;
+
+    #[luau(name = "typeof")]
+    fn luau_typeof(value: &LuauValue) -> String;
+}
+
+/// The `namespace` attribute resolves every import in the block against a Luau path.
+/// This will call `math.floor` in the generated bindings.
+#[luau(namespace = "math")]
+extern "C" {
+    fn floor(value: f64) -> f64;
+
+    // Tuples capture multiple values returned from Luau.
+    fn modf(value: f64) -> (f64, f64);
+}
+
+/// Functions of an instance namespace are called as methods.
+/// This will call `game.Workspace:GetChildren()` in the generated bindings.
+#[luau(namespace = "game.Workspace")]
+extern "C" {
+    fn GetChildren() -> Vec<LuauValue>;
 }
 
 /// The #[luau] macro can also be applied to public functions.
@@ -30,12 +91,261 @@ pub fn echo_non_zero(value: u32) -> Option<u32> {
     }
 }
 
+/// Functions returning a `Result` raise the error message in Luau using `error` when they fail.
+#[luau]
+pub fn checked_divide(value: f64, divisor: f64) -> Result<f64, String> {
+    if divisor == 0.0 {
+        Err("attempt to divide by zero".to_string())
+    } else {
+        Ok(value / divisor)
+    }
+}
+
+/// Tuples are returned to Luau as multiple values, this returns `quotient, remainder` in Luau.
+#[luau]
+pub fn div_rem(value: i32, divisor: i32) -> (i32, i32) {
+    (value / divisor, value % divisor)
+}
+
+/// Each item of a tuple can be any type, this returns `name, position` in Luau.
+#[luau]
+pub fn named_centroid(name: String, points: &[Vector3]) -> (String, Option<Vector3>) {
+    (name, centroid(points))
+}
+
+/// C-like enums are passed to Luau as strings, and are exposed as a frozen table, e.g `Module.Team.Red`.
+///
+/// The `number` attribute uses the discriminants instead, e.g `#[luau(number)]`.
+#[luau]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Team {
+    Red,
+    Blue,
+}
+
+#[luau]
+pub fn opposing_team(team: Team) -> Team {
+    match team {
+        Team::Red => Team::Blue,
+        Team::Blue => Team::Red,
+    }
+}
+
+/// Structs deriving `LuauTable` are passed by value as Luau tables with the same field names.
+///
+/// Nested structs become nested tables, and fields can be renamed using the `name` attribute.
+#[derive(LuauTable)]
+pub struct Spawn {
+    #[luau(name = "Position")]
+    pub position: Point,
+    pub team: Team,
+    pub label: String,
+}
+
+#[derive(LuauTable)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+#[luau]
+pub fn offset_spawn(spawn: Spawn, offset: Point) -> Spawn {
+    let position = Point {
+        x: spawn.position.x + offset.x,
+        y: spawn.position.y + offset.y,
+        z: spawn.position.z + offset.z,
+    };
+
+    Spawn { position, ..spawn }
+}
+
+/// `HashMap` and `BTreeMap` are passed to Luau as dictionaries.
+#[luau]
+pub fn top_scores(scores: HashMap<String, u32>, minimum: u32) -> HashMap<String, u32> {
+    scores
+        .into_iter()
+        .filter(|(_, score)| *score >= minimum)
+        .collect()
+}
+
+/// 64-bit integers are passed to Luau as numbers, and error if they don't fit within a number's 53 bits of precision.
+#[luau]
+pub fn entity_id(index: u32, generation: u16) -> u64 {
+    (generation as u64) << 32 | index as u64
+}
+
+/// Roblox data types like `Vector3`, `CFrame` and `Color3` are plain Rust structs, which are passed by value.
+///
+/// Luau receives them as the real Roblox values, constructed using `Vector3.new` and similar.
+#[luau]
+pub fn step_projectile(position: Vector3, velocity: Vector3, delta_time: f32) -> Vector3 {
+    position + velocity * delta_time
+}
+
+#[luau]
+pub fn orbit(center: CFrame, radius: f32, angle: f32) -> CFrame {
+    center * CFrame::from_euler_angles_xyz(0.0, angle, 0.0) * CFrame::new(0.0, 0.0, radius)
+}
+
+/// Lists of data types are copied into memory as their components, the same way as lists of numbers.
+#[luau]
+pub fn centroid(points: &[Vector3]) -> Option<Vector3> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let sum = points.iter().fold(Vector3::ZERO, |sum, point| sum + *point);
+    Some(sum / points.len() as f32)
+}
+
+/// Mutable slices and vectors are copied back into the Luau table they came from once the function returns.
+#[luau]
+pub fn normalize(values: &mut [f64]) {
+    let length = values.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if length != 0.0 {
+        values.iter_mut().for_each(|v| *v /= length);
+    }
+}
+
+#[luau]
+pub fn push_unique(names: &mut Vec<String>, name: String) {
+    if !names.contains(&name) {
+        names.push(name);
+    }
+}
+
+/// Sorts the values using Luau's `table.sort`, then returns the median.
+#[luau]
+pub fn median(mut values: Vec<f64>) -> Option<f64> {
+    table_sort(&mut values);
+    values.get(values.len() / 2).copied()
+}
+
+/// Joins the labels of the spawns using Luau's `table.concat`.
+#[luau]
+pub fn spawn_labels(spawns: Vec<Spawn>) -> String {
+    let labels: Vec<String> = spawns.into_iter().map(|spawn| spawn.label).collect();
+    table_concat(&labels, ", ")
+}
+
+/// Strings from Luau raise an error if they aren't valid UTF-8.
+/// Parameters marked with `lossy` replace invalid UTF-8 with `U+FFFD` instead.
+#[luau]
+pub fn greet(#[luau(lossy)] name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+/// Exports can borrow strings, slices and Luau values, which are cleaned up once the function returns.
+#[luau]
+pub fn count_words(text: &str, stop_words: &[String]) -> u32 {
+    text.split_whitespace()
+        .filter(|word| !stop_words.iter().any(|stop| stop == word))
+        .count() as u32
+}
+
+#[luau]
+pub fn type_name(value: &LuauValue) -> String {
+    luau_typeof(value)
+}
+
+/// `LuauBytes` receives Luau strings which hold binary data, without checking they're valid UTF-8.
+#[luau]
+pub fn checksum(data: LuauBytes) -> u32 {
+    data.iter().fold(0u32, |sum, byte| {
+        sum.wrapping_mul(31).wrapping_add(*byte as u32)
+    })
+}
+
+/// `LuauBuffer` is passed to Luau as a `buffer`, which is copied in one operation instead of element by element.
+#[luau]
+pub fn xor_buffer(mut data: LuauBuffer, key: u8) -> LuauBuffer {
+    for byte in data.iter_mut() {
+        *byte ^= key;
+    }
+
+    data
+}
+
+/// Luau functions can be received as a `LuauFunction`, and called with typed arguments and return values.
+#[luau]
+pub fn count_matching(values: Vec<f64>, predicate: LuauFunction) -> u32 {
+    values
+        .into_iter()
+        .filter(|value| predicate.call::<_, bool>((*value,)))
+        .count() as u32
+}
+
+/// The #[luau] macro can also be applied to structs, which are exposed to Luau as classes.
+///
+/// Luau only holds onto a handle of the Rust value, which is dropped when `:Destroy()` is called.
+#[luau]
+pub struct Counter {
+    value: u32,
+}
+
+/// Public methods inside of a #[luau] impl block are added to the class.
+///
+/// Methods taking `&self` or `&mut self` are called using `counter:method()` in Luau,
+/// and the `constructor` attribute exposes a function as `Counter.new`.
+#[luau]
+impl Counter {
+    #[luau(constructor)]
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+
+    pub fn increment(&mut self) -> u32 {
+        self.value += 1;
+        self.value
+    }
+
+    pub fn get(&self) -> u32 {
+        self.value
+    }
+}
+
 /// The #[luau(main)] macro variant is a special function which automatically gets executed at runtime.
 ///
 /// This can be used to write game code without explicitly needing to call a public function.
 #[luau(main)]
 pub fn main() {
     let value = vector3_new(1.5, 2.5, 3.5);
-    print(None);
-    print(Some(value));
+    let other = vector3_new(4.5, 5.5, 6.5);
+    print([].into());
+
+    // Cloning a `LuauValue` refers to the same Luau value, and dropping it releases its slot in the runtime.
+    print([value.clone(), other].into());
+    print([value].into());
+
+    for child in GetChildren() {
+        print([child].into());
+    }
+
+    assert_eq!(floor(2.5), 2.0);
+    assert_eq!(modf(2.5), (2.0, 0.5));
+
+    // Values can be used dynamically, without declaring an import for every property or method.
+    let position = vector3_new(3.0, 4.0, 0.0);
+    assert_eq!(position.type_of(), "Vector3");
+    assert_eq!(f64::try_from(position.get("Magnitude")).unwrap(), 5.0);
+    assert!(f64::try_from(position.get("Unit")).is_err());
+
+    let unit = position.get("Unit");
+    print([unit.call_method("Dot", (position,))].into());
+
+    if let Err(error) = raise_error("caught by pcall") {
+        print([error.message().into_luau_value()].into());
+    }
+
+    // The closure must outlive the delay, so it is leaked using `forget`.
+    let callback = Closure::new(|| print([].into()));
+    task_delay(1.0, &callback);
+    callback.forget();
+
+    let part = part_new("Part");
+    set_part_name(&part, "Baseplate");
+    assert_eq!(part_name(&part), "Baseplate");
+    destroy(&part);
+    print([part.into()].into());
 }